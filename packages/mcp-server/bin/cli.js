@@ -19,7 +19,7 @@ ${COLORS.bright}Usage:${COLORS.reset}
   distill-mcp <command> [options]
 
 ${COLORS.bright}Commands:${COLORS.reset}
-  serve             Start the MCP server (stdio mode)
+  serve             Start the MCP server (stdio, or HTTP with --http)
   setup             Configure IDEs to use Distill
   doctor            Check installation and configuration
   analyze           Analyze files for token usage
@@ -36,6 +36,9 @@ ${COLORS.bright}Server Options:${COLORS.reset}
   --lazy            Enable lazy mode (95% token savings, only 2 meta-tools)
   --mode <mode>     Loading mode: lazy|core|all (default: core)
   --verbose         Enable verbose logging (shows tool calls, timing, tokens)
+  --http            Serve over streamable HTTP on 127.0.0.1 instead of stdio
+                    (bearer token from DISTILL_HTTP_TOKEN, or generated)
+  --port <port>     HTTP port, with --http (default: 3100)
 
 ${COLORS.bright}Analyze Options:${COLORS.reset}
   --patterns, -p    Glob patterns to match (default: **/*.{ts,tsx,js,jsx,py,go,rs})
@@ -58,6 +61,7 @@ ${COLORS.bright}Examples:${COLORS.reset}
   distill-mcp serve                    Start MCP server (used by IDE)
   distill-mcp serve --lazy             Start with lazy mode (95% savings)
   distill-mcp serve --verbose          Start with verbose logging
+  distill-mcp serve --http --port 3100 Start on http://127.0.0.1:3100/mcp
   distill-mcp analyze                  Analyze token usage in codebase
   distill-mcp analyze -t 5000 --json   Custom threshold, JSON output
 
@@ -101,6 +105,27 @@ async function main() {
         mode,
       };
 
+      if (args.includes("--port") && !args.includes("--http")) {
+        console.error("--port requires --http");
+        process.exit(1);
+      }
+
+      if (args.includes("--http")) {
+        const portIndex = args.indexOf("--port");
+        let port;
+        if (portIndex !== -1) {
+          const value = args[portIndex + 1];
+          port = /^\d+$/.test(value ?? "") ? Number(value) : NaN;
+          if (!Number.isInteger(port) || port < 1 || port > 65535) {
+            console.error(`Invalid port: ${value ?? "(missing)"} (expected an integer from 1 to 65535)`);
+            process.exit(1);
+          }
+        }
+
+        config.transport = "http";
+        config.http = { port, token: process.env.DISTILL_HTTP_TOKEN };
+      }
+
       await runServer(config);
       break;
     }
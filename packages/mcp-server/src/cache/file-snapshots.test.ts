@@ -0,0 +1,40 @@
+/**
+ * File Snapshots Tests
+ */
+
+import { describe, it, expect } from "vitest";
+import { FileSnapshotStore } from "./file-snapshots.js";
+
+describe("FileSnapshotStore", () => {
+  it("should evict the oldest snapshots past the entry limit", () => {
+    const store = new FileSnapshotStore(2);
+    store.record("a.ts", "a");
+    store.record("b.ts", "b");
+    store.record("a.ts", "a2");
+    store.record("c.ts", "c");
+
+    expect(store.get("b.ts")).toBeUndefined();
+    expect(store.get("a.ts")?.content).toBe("a2");
+    expect(store.size).toBe(2);
+  });
+
+  it("should evict the oldest snapshots past the byte limit", () => {
+    const store = new FileSnapshotStore(100, 10);
+    store.record("a.ts", "aaaa");
+    store.record("b.ts", "bbbb");
+    store.record("c.ts", "cccc");
+
+    expect(store.get("a.ts")).toBeUndefined();
+    expect(store.size).toBe(2);
+    expect(store.bytes).toBe(8);
+  });
+
+  it("should not keep content larger than the whole store", () => {
+    const store = new FileSnapshotStore(100, 10);
+    store.record("a.ts", "aaaa");
+    store.record("a.ts", "x".repeat(20));
+
+    expect(store.get("a.ts")).toBeUndefined();
+    expect(store.bytes).toBe(0);
+  });
+});
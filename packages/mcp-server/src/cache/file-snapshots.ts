@@ -0,0 +1,110 @@
+/**
+ * File Snapshots
+ *
+ * Remembers the last content read for each file so a later read
+ * can be answered with a diff instead of the whole file.
+ */
+
+import * as crypto from "crypto";
+
+export interface FileSnapshot {
+  /** File content at the time of the read */
+  content: string;
+  /** MD5 of the content */
+  hash: string;
+  /** Timestamp of the read */
+  recordedAt: number;
+  /** UTF-8 size of the content */
+  bytes: number;
+}
+
+const DEFAULT_MAX_SNAPSHOTS = 200;
+const DEFAULT_MAX_SNAPSHOT_BYTES = 32 * 1024 * 1024; // 32MB
+
+export class FileSnapshotStore {
+  private snapshots: Map<string, FileSnapshot> = new Map();
+  private totalBytes = 0;
+
+  constructor(
+    private readonly maxEntries: number = DEFAULT_MAX_SNAPSHOTS,
+    private readonly maxBytes: number = DEFAULT_MAX_SNAPSHOT_BYTES
+  ) {}
+
+  /**
+   * Record the content of a file, evicting the oldest snapshots when full
+   * Content larger than the whole store is not kept.
+   */
+  record(filePath: string, content: string): FileSnapshot {
+    const snapshot: FileSnapshot = {
+      content,
+      hash: crypto.createHash("md5").update(content).digest("hex"),
+      recordedAt: Date.now(),
+      bytes: Buffer.byteLength(content),
+    };
+
+    // Re-insert so Map order reflects recency
+    this.delete(filePath);
+    if (snapshot.bytes > this.maxBytes) return snapshot;
+
+    this.snapshots.set(filePath, snapshot);
+    this.totalBytes += snapshot.bytes;
+
+    while (this.snapshots.size > this.maxEntries || this.totalBytes > this.maxBytes) {
+      const oldest = this.snapshots.keys().next().value;
+      if (oldest === undefined) break;
+      this.delete(oldest);
+    }
+
+    return snapshot;
+  }
+
+  /**
+   * Get the last recorded snapshot for a file
+   */
+  get(filePath: string): FileSnapshot | undefined {
+    return this.snapshots.get(filePath);
+  }
+
+  /**
+   * Forget a file
+   */
+  delete(filePath: string): boolean {
+    const snapshot = this.snapshots.get(filePath);
+    if (!snapshot) return false;
+    this.totalBytes -= snapshot.bytes;
+    return this.snapshots.delete(filePath);
+  }
+
+  /**
+   * Forget all files
+   */
+  clear(): void {
+    this.snapshots.clear();
+    this.totalBytes = 0;
+  }
+
+  get size(): number {
+    return this.snapshots.size;
+  }
+
+  /** Total size of the stored content */
+  get bytes(): number {
+    return this.totalBytes;
+  }
+}
+
+/**
+ * Global snapshot store instance
+ */
+let globalSnapshots: FileSnapshotStore | null = null;
+
+export function getSnapshotStore(): FileSnapshotStore {
+  if (!globalSnapshots) {
+    globalSnapshots = new FileSnapshotStore();
+  }
+  return globalSnapshots;
+}
+
+export function resetSnapshotStore(): void {
+  globalSnapshots = null;
+}
@@ -1,9 +1,10 @@
 /**
  * Smart Cache Module
  *
- * Exports for the LRU cache with TTL and file hash validation.
+ * Exports for the LRU cache with TTL, file hash validation and file snapshots.
  */
 
 export * from "./types.js";
 export * from "./smart-cache.js";
 export * from "./file-hash.js";
+export * from "./file-snapshots.js";
@@ -7,6 +7,8 @@
 
 import * as fs from "fs";
 import * as path from "path";
+import { writeFileAtomic } from "../utils/atomic-write.js";
+import { IgnoreMatcher, globToRegExp } from "../utils/ignore-rules.js";
 import { countTokens } from "../utils/token-counter.js";
 
 export interface AnalyzeOptions {
@@ -59,27 +61,6 @@ const LANGUAGE_MAP: Record<string, string> = {
   ".yml": "yaml",
 };
 
-/**
- * Match files against glob patterns (simple implementation)
- */
-function matchGlob(filepath: string, pattern: string): boolean {
-  // Handle brace expansion: {a,b,c} -> (a|b|c)
-  let regexPattern = pattern.replace(/\{([^}]+)\}/g, (_match, group) => {
-    const alternatives = group.split(",").map((s: string) => s.trim());
-    return `(${alternatives.join("|")})`;
-  });
-
-  regexPattern = regexPattern
-    .replace(/\*\*\//g, "(.*/)?")
-    .replace(/\*\*/g, ".*")
-    .replace(/\*/g, "[^/]*")
-    .replace(/\?/g, ".")
-    .replace(/\./g, "\\.");
-
-  const regex = new RegExp(`^${regexPattern}$`);
-  return regex.test(filepath);
-}
-
 /**
  * Walk directory and find matching files
  */
@@ -89,11 +70,17 @@ function walkDirectory(
   maxFiles: number = 1000
 ): string[] {
   const results: string[] = [];
+  const globs = patterns.map((pattern) => globToRegExp(pattern, { braces: true }));
 
-  function walk(currentDir: string, relativePath: string = ""): void {
+  function walk(
+    currentDir: string,
+    relativePath: string = "",
+    parentIgnore: IgnoreMatcher = IgnoreMatcher.empty()
+  ): void {
     if (results.length >= maxFiles) return;
 
     try {
+      const ignore = parentIgnore.forDirectory(currentDir, relativePath.split(path.sep).join("/"));
       const entries = fs.readdirSync(currentDir, { withFileTypes: true });
 
       for (const entry of entries) {
@@ -102,19 +89,21 @@ function walkDirectory(
         const fullPath = path.join(currentDir, entry.name);
         const relPath = path.join(relativePath, entry.name);
 
-        // Skip hidden dirs and node_modules
-        if (entry.name.startsWith(".") || entry.name === "node_modules") {
+        // Hidden entries and node_modules are skipped unless an ignore rule re-includes them
+        const skippedByDefault = entry.name.startsWith(".") || entry.name === "node_modules";
+        const globPath = relPath.split(path.sep).join("/");
+        if (
+          entry.name === ".git" ||
+          (ignore.match(globPath, entry.isDirectory()) ?? skippedByDefault)
+        ) {
           continue;
         }
 
         if (entry.isDirectory()) {
-          walk(fullPath, relPath);
+          walk(fullPath, relPath, ignore);
         } else if (entry.isFile()) {
-          for (const pattern of patterns) {
-            if (matchGlob(relPath, pattern)) {
-              results.push(relPath);
-              break;
-            }
+          if (globs.some((glob) => glob.test(globPath))) {
+            results.push(relPath);
           }
         }
       }
@@ -123,7 +112,7 @@ function walkDirectory(
     }
   }
 
-  walk(dir);
+  walk(dir, "", IgnoreMatcher.forRoot(dir));
   return results;
 }
 
@@ -308,7 +297,7 @@ export async function runAnalyze(args: string[]): Promise<void> {
   const output = json ? JSON.stringify(report, null, 2) : formatReportAsText(report);
 
   if (outputFile) {
-    fs.writeFileSync(outputFile, output);
+    writeFileAtomic(outputFile, output);
     console.log(`Report written to ${outputFile}`);
   } else {
     console.log(output);
@@ -7,9 +7,10 @@
  * - UserPromptSubmit: Injects MCP tool reminders
  */
 
-import { existsSync, mkdirSync, writeFileSync, chmodSync } from "fs";
+import { existsSync, mkdirSync } from "fs";
 import { join } from "path";
 import { success, warn, info, error, log, COLORS, readJSONFile, writeJSONFile } from "./utils.js";
+import { writeFileAtomic } from "../utils/atomic-write.js";
 
 // ============================================================================
 // Hook Script Templates
@@ -217,8 +218,7 @@ export async function installHooks(options: InstallHooksOptions = {}): Promise<b
       continue;
     }
 
-    writeFileSync(filepath, content, "utf-8");
-    chmodSync(filepath, 0o755); // Make executable
+    writeFileAtomic(filepath, content, { mode: 0o755 }); // Executable
     success(`Created ${COLORS.dim}.claude/hooks/${COLORS.reset}${filename}`);
     scriptsWritten++;
   }
@@ -1,6 +1,7 @@
 import { homedir, platform } from "os";
 import { join } from "path";
-import { existsSync, readFileSync, writeFileSync, mkdirSync } from "fs";
+import { existsSync, readFileSync } from "fs";
+import { writeFileAtomic } from "../utils/atomic-write.js";
 
 export type IDE = "claude" | "cursor" | "windsurf" | "antigravity";
 
@@ -107,11 +108,8 @@ export function readJSONFile(path: string): Record<string, unknown> | null {
 
 export function writeJSONFile(path: string, data: Record<string, unknown>): boolean {
   try {
-    const dir = join(path, "..");
-    if (!existsSync(dir)) {
-      mkdirSync(dir, { recursive: true });
-    }
-    writeFileSync(path, JSON.stringify(data, null, 2) + "\n");
+    // Atomic so a crash never leaves the IDE config truncated
+    writeFileAtomic(path, JSON.stringify(data, null, 2) + "\n");
     return true;
   } catch {
     return false;
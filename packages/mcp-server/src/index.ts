@@ -8,6 +8,11 @@
 
 // Server
 export { createServer, runServer, type ServerConfig, type ServerInstance, type LoadingMode } from "./server.js";
+export {
+  startHttpTransport,
+  DEFAULT_HTTP_PORT,
+  type HttpTransportOptions,
+} from "./transports/http.js";
 
 // State management
 
@@ -0,0 +1,118 @@
+/**
+ * Build Output Parser Tests
+ */
+
+import { describe, it, expect } from "vitest";
+import { analyzeBuildOutput, detectBuildTool, groupErrors, parseOutput } from "./index.js";
+
+const CARGO_OUTPUT = `   Compiling demo v0.1.0 (/app)
+warning: unused variable: \`x\`
+ --> src/main.rs:3:9
+  |
+3 |     let x = 5;
+  |         ^ help: if this is intentional, prefix it with an underscore: \`_x\`
+  |
+  = note: \`#[warn(unused_variables)]\` on by default
+
+error[E0308]: mismatched types
+  --> src/lib.rs:12:18
+   |
+12 |     let y: u32 = "a";
+   |            ---   ^^^ expected \`u32\`, found \`&str\`
+
+warning: this \`return\` is needless
+  --> src/lib.rs:20:5
+   |
+   = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+
+error: aborting due to 1 previous error; 2 warnings emitted
+error: could not compile \`demo\` (lib) due to 1 previous error`;
+
+const WEBPACK_OUTPUT = `ERROR in ./src/index.ts 3:0-25
+Module not found: Error: Can't resolve './foo' in '/app/src'
+
+ERROR in /app/src/a.ts
+./src/a.ts 12:5-8
+[tsl] ERROR in /app/src/a.ts(12,6)
+      TS2304: Cannot find name 'bar'.
+
+WARNING in ./src/x.js 5:0-10
+export 'foo' (imported as 'foo') was not found in './bar'
+
+webpack 5.88.0 compiled with 2 errors and 1 warning in 1234 ms`;
+
+describe("build output parsers", () => {
+  describe("cargo output", () => {
+    it("should be detected as rust rather than eslint", () => {
+      expect(detectBuildTool(CARGO_OUTPUT)).toBe("rust");
+    });
+
+    it("should extract errors and warnings with locations", () => {
+      const { errors } = parseOutput(CARGO_OUTPUT);
+
+      expect(errors.map((e) => [e.severity, e.code, e.file, e.line, e.column])).toEqual([
+        ["warning", "unused_variables", "src/main.rs", 3, 9],
+        ["error", "E0308", "src/lib.rs", 12, 18],
+        ["warning", "clippy::needless_return", "src/lib.rs", 20, 5],
+      ]);
+    });
+
+    it("should skip cargo summary lines", () => {
+      const { errors } = parseOutput(CARGO_OUTPUT);
+      expect(errors.some((e) => e.message.startsWith("aborting"))).toBe(false);
+      expect(errors.some((e) => e.message.startsWith("could not compile"))).toBe(false);
+    });
+
+    it("should report the build as failed", () => {
+      const result = analyzeBuildOutput(CARGO_OUTPUT);
+      expect(result.success).toBe(false);
+      expect(result.stats.totalErrors).toBe(1);
+      expect(result.stats.totalWarnings).toBe(2);
+    });
+  });
+
+  describe("webpack output", () => {
+    it("should be detected as webpack", () => {
+      expect(detectBuildTool(WEBPACK_OUTPUT)).toBe("webpack");
+    });
+
+    it("should not claim ESLint output that mentions webpack files", () => {
+      const output = `/app/webpack.config.js
+  3:7  error    'path' is assigned a value but never used  no-unused-vars
+  9:1  warning  Unexpected console statement               no-console
+
+✖ 2 problems (1 error, 1 warning)`;
+      expect(detectBuildTool(output)).toBe("eslint");
+    });
+
+    it("should extract one record per block", () => {
+      const { errors } = parseOutput(WEBPACK_OUTPUT);
+
+      expect(errors.map((e) => [e.severity, e.code, e.file, e.line, e.column])).toEqual([
+        ["error", "WEBPACK", "./src/index.ts", 3, 0],
+        ["error", "TS2304", "/app/src/a.ts", 12, 6],
+        ["warning", "WEBPACK", "./src/x.js", 5, 0],
+      ]);
+    });
+
+    it("should group unresolved modules under one signature", () => {
+      const output = [
+        "ERROR in ./src/index.ts 3:0-25",
+        "Module not found: Error: Can't resolve './missing' in '/app/src'",
+        "",
+        "ERROR in ./src/util.ts 1:0-30",
+        "Module not found: Error: Can't resolve 'lodash' in '/app/src/lib'",
+      ].join("\n");
+
+      const groups = groupErrors(parseOutput(output).errors);
+      expect(groups).toHaveLength(1);
+      expect(groups[0]?.count).toBe(2);
+      expect(groups[0]?.signature).not.toContain("missing");
+    });
+
+    it("should suggest a fix for unresolved modules", () => {
+      const { errors } = parseOutput(WEBPACK_OUTPUT);
+      expect(errors[0]?.context).toContain("./foo");
+    });
+  });
+});
@@ -8,12 +8,15 @@ import { encodingForModel } from "js-tiktoken";
 import type { BuildParser, ParsedError, ErrorGroup, BuildAnalysisResult, BuildTool } from "./types.js";
 import { typescriptParser } from "./typescript.js";
 import { eslintParser } from "./eslint.js";
+import { rustParser } from "./rust.js";
+import { webpackParser } from "./webpack.js";
 import { genericParser } from "./generic.js";
 
 export * from "./types.js";
 
 // All available parsers (order matters for detection priority)
-const parsers: BuildParser[] = [typescriptParser, eslintParser, genericParser];
+// Rust and webpack come before ESLint, whose loose "error + warning + N:N" check matches their output too
+const parsers: BuildParser[] = [typescriptParser, rustParser, webpackParser, eslintParser, genericParser];
 
 // Token encoder
 const encoding = encodingForModel("gpt-4");
@@ -34,6 +37,16 @@ export function detectBuildTool(output: string): BuildTool {
     return "tsc";
   }
 
+  // Rust (checked before ESLint, whose heuristic also matches cargo output)
+  if (output.includes("error[E") || output.includes("rustc") || /^\s*--> .+\.rs:\d+:\d+/m.test(output)) {
+    return "rust";
+  }
+
+  // Webpack (anchored: a bare "webpack" also appears in paths such as webpack.config.js)
+  if (/^(?:ERROR|WARNING) in /m.test(output) || /webpack \d+\.\d+\.\d+ compiled/.test(output)) {
+    return "webpack";
+  }
+
   // ESLint
   if (
     (output.includes("error") && output.includes("warning") && /\d+:\d+/.test(output)) ||
@@ -42,11 +55,6 @@ export function detectBuildTool(output: string): BuildTool {
     return "eslint";
   }
 
-  // Webpack
-  if (output.includes("ERROR in") || output.includes("webpack")) {
-    return "webpack";
-  }
-
   // Vite
   if (output.includes("[vite]")) {
     return "vite";
@@ -57,11 +65,6 @@ export function detectBuildTool(output: string): BuildTool {
     return "esbuild";
   }
 
-  // Rust
-  if (output.includes("error[E") || output.includes("rustc")) {
-    return "rust";
-  }
-
   // Go
   if (/\.go:\d+:\d+:/.test(output)) {
     return "go";
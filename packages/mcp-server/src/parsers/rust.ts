@@ -0,0 +1,182 @@
+/**
+ * Rust Error Parser
+ *
+ * Parses cargo/rustc/clippy diagnostics into structured errors and warnings.
+ */
+
+import type { BuildParser, ParsedError, Severity } from "./types.js";
+
+// Diagnostic header: error[E0308]: mismatched types / warning: unused variable: `x`
+const HEADER_PATTERN = /^(error|warning)(?:\[([\w:]+)\])?:\s*(.+)$/;
+
+// Primary location:   --> src/main.rs:12:5
+const LOCATION_PATTERN = /^\s*--> (.+?):(\d+):(\d+)\s*$/;
+
+// Lint attribute note: = note: `#[warn(unused_variables)]` on by default
+const LINT_NOTE_PATTERN = /#\[(?:warn|deny|forbid)\(([\w:]+)\)\]/;
+
+// Clippy help link: = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return
+const CLIPPY_LINK_PATTERN = /rust-clippy\/[^\s#]*#([\w]+)/;
+
+// Cargo summary lines that look like diagnostics but are not
+const SUMMARY_PATTERNS = [
+  /^aborting due to/,
+  /^could not compile/,
+  /generated \d+ warnings?/,
+  /^build failed/,
+  /^\d+ warnings? emitted/,
+];
+
+/**
+ * Generate suggestion based on error code
+ */
+function getSuggestion(code: string, message: string): string | undefined {
+  switch (code) {
+    case "E0308":
+      return "Check the expected and found types; a conversion or reference may be missing.";
+
+    case "E0425": {
+      const nameMatch = message.match(/cannot find (?:value|function) `(\w+)`/);
+      if (nameMatch?.[1]) {
+        return `Check if '${nameMatch[1]}' needs to be imported with 'use' or declared.`;
+      }
+      break;
+    }
+
+    case "E0433":
+      return "Check the module path and add the missing 'use' or dependency.";
+
+    case "E0599":
+      return "The method may need a trait in scope ('use Trait;') or a different receiver type.";
+
+    case "E0382":
+      return "The value was moved; clone it, borrow it, or restructure ownership.";
+
+    case "E0499":
+    case "E0502":
+      return "Conflicting borrows; shorten the first borrow's scope or split the data.";
+
+    case "E0277":
+      return "A trait bound is not satisfied; implement or derive the trait, or change the type.";
+
+    case "unused_imports":
+      return "Remove the unused import.";
+
+    case "unused_variables":
+      return "Remove the variable or prefix it with an underscore.";
+
+    case "dead_code":
+      return "Remove the unused item or mark it #[allow(dead_code)] if intentional.";
+  }
+
+  if (code.startsWith("clippy::")) {
+    return `See https://rust-lang.github.io/rust-clippy/master/index.html#${code.slice("clippy::".length)}`;
+  }
+
+  return undefined;
+}
+
+/**
+ * Create error signature for grouping
+ */
+function createSignature(code: string, message: string): string {
+  const normalizedMessage = message
+    .replace(/`[^`]+`/g, "`X`")
+    .replace(/'[^']+'/g, "'X'")
+    .replace(/\d+/g, "N")
+    .replace(/\s+/g, " ")
+    .trim();
+
+  return `${code}:${normalizedMessage}`;
+}
+
+interface PendingDiagnostic {
+  severity: Severity;
+  code: string | null;
+  message: string;
+  file: string;
+  line: number;
+  column: number;
+  raw: string;
+}
+
+function finalize(pending: PendingDiagnostic): ParsedError {
+  const code = pending.code ?? (pending.severity === "error" ? "RUST" : "WARN");
+  return {
+    signature: createSignature(code, pending.message),
+    code,
+    message: pending.message,
+    file: pending.file,
+    line: pending.line,
+    column: pending.column,
+    severity: pending.severity,
+    raw: pending.raw,
+    context: getSuggestion(code, pending.message),
+  };
+}
+
+export const rustParser: BuildParser = {
+  name: "rust",
+  supportedTools: ["rust"],
+
+  canParse(output: string): boolean {
+    return /^(?:error|warning)(?:\[[\w:]+\])?: .+$/m.test(output) && /^\s*--> .+:\d+:\d+/m.test(output);
+  },
+
+  parse(output: string): ParsedError[] {
+    const errors: ParsedError[] = [];
+    const lines = output.split("\n");
+
+    let current: PendingDiagnostic | null = null;
+
+    for (const line of lines) {
+      const headerMatch = line.match(HEADER_PATTERN);
+      if (headerMatch) {
+        if (current) errors.push(finalize(current));
+        current = null;
+
+        const message = headerMatch[3] ?? "Unknown error";
+        if (SUMMARY_PATTERNS.some((p) => p.test(message))) continue;
+
+        current = {
+          severity: headerMatch[1] === "warning" ? "warning" : "error",
+          code: headerMatch[2] ?? null,
+          message,
+          file: "unknown",
+          line: 0,
+          column: 0,
+          raw: line.trim(),
+        };
+        continue;
+      }
+
+      if (!current) continue;
+
+      // Only the first location is the primary span
+      const locationMatch = line.match(LOCATION_PATTERN);
+      if (locationMatch && current.file === "unknown") {
+        current.file = locationMatch[1] ?? "unknown";
+        current.line = parseInt(locationMatch[2] ?? "0", 10);
+        current.column = parseInt(locationMatch[3] ?? "0", 10);
+        continue;
+      }
+
+      // Lint name from the attribute note or clippy help link
+      if (!current.code) {
+        const lintMatch = line.match(LINT_NOTE_PATTERN);
+        if (lintMatch?.[1]) {
+          current.code = lintMatch[1];
+          continue;
+        }
+        const clippyMatch = line.match(CLIPPY_LINK_PATTERN);
+        if (clippyMatch?.[1]) {
+          current.code = `clippy::${clippyMatch[1]}`;
+        }
+      }
+    }
+
+    if (current) errors.push(finalize(current));
+
+    return errors;
+  },
+};
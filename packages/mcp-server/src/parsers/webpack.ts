@@ -0,0 +1,174 @@
+/**
+ * Webpack Error Parser
+ *
+ * Parses webpack ERROR/WARNING blocks, including ts-loader and
+ * fork-ts-checker output, into structured errors.
+ */
+
+import type { BuildParser, ParsedError, Severity } from "./types.js";
+
+// Block header: ERROR in ./src/index.ts 3:0-25 / WARNING in src/a.ts:12:5
+const HEADER_PATTERN = /^(ERROR|WARNING) in (.+)$/;
+
+// ts-loader detail line: [tsl] ERROR in /app/src/a.ts(12,6)
+const TSL_PATTERN = /^\[tsl\] (?:ERROR|WARNING) in (.+?)\((\d+),(\d+)\)/;
+
+// Location forms found after the file path
+const LOCATION_PAREN = /^(.+?)\((\d+),(\d+)\)$/; // file.ts(12,5)
+const LOCATION_COLON = /^(.+?):(\d+):(\d+)$/; // file.ts:12:5
+const LOCATION_SPACE = /^(\S+)\s+(\d+):(\d+)(?:-\d+(?::\d+)?)?$/; // ./file.ts 12:5-8
+
+// Lines that carry no message of their own
+const NOISE_PATTERNS = [
+  /^Module build failed/,
+  /^@ /,
+  /^\S+\s+\d+:\d+(?:-\d+(?::\d+)?)?$/, // bare "./src/a.ts 12:5-8" location
+  /^webpack \d/,
+];
+
+// TypeScript code inside a loader message: TS2304: Cannot find name 'x'.
+const TS_CODE_PATTERN = /^(TS\d+):\s*(.+)$/;
+
+/**
+ * Generate suggestion based on message
+ */
+function getSuggestion(message: string): string | undefined {
+  const resolveMatch = message.match(/Can't resolve '([^']+)'/);
+  if (resolveMatch?.[1]) {
+    const target = resolveMatch[1];
+    return target.startsWith(".")
+      ? `Check the relative path '${target}' and the file extension.`
+      : `Install the module with: npm install ${target}`;
+  }
+
+  if (/export '.+' \(imported as '.+'\) was not found/.test(message)) {
+    return "Check the export name in the imported module.";
+  }
+
+  return undefined;
+}
+
+/**
+ * Create error signature for grouping
+ */
+function createSignature(code: string, message: string): string {
+  // Quotes must sit on a word boundary, so the apostrophe in "Can't" is not one
+  const normalizedMessage = message
+    .replace(/(?<!\w)'[^']+'(?!\w)/g, "'X'")
+    .replace(/(?<!\w)"[^"]+"(?!\w)/g, '"X"')
+    .replace(/\d+/g, "N")
+    .replace(/\s+/g, " ")
+    .slice(0, 100)
+    .trim();
+
+  return `${code}:${normalizedMessage}`;
+}
+
+/**
+ * Split "path location" from a header into file/line/column
+ */
+function parseLocation(target: string): { file: string; line: number; column: number } {
+  const trimmed = target.trim();
+  const match =
+    trimmed.match(LOCATION_PAREN) ?? trimmed.match(LOCATION_COLON) ?? trimmed.match(LOCATION_SPACE);
+  if (match) {
+    return {
+      file: match[1] ?? trimmed,
+      line: parseInt(match[2] ?? "0", 10),
+      column: parseInt(match[3] ?? "0", 10),
+    };
+  }
+  return { file: trimmed, line: 0, column: 0 };
+}
+
+interface PendingBlock {
+  severity: Severity;
+  file: string;
+  line: number;
+  column: number;
+  code: string;
+  message: string;
+  raw: string;
+}
+
+export const webpackParser: BuildParser = {
+  name: "webpack",
+  supportedTools: ["webpack"],
+
+  canParse(output: string): boolean {
+    return /^(?:ERROR|WARNING) in /m.test(output);
+  },
+
+  parse(output: string): ParsedError[] {
+    const errors: ParsedError[] = [];
+    const lines = output.split("\n");
+
+    let current: PendingBlock | null = null;
+
+    const flush = () => {
+      if (current?.message) {
+        errors.push({
+          signature: createSignature(current.code, current.message),
+          code: current.code,
+          message: current.message,
+          file: current.file,
+          line: current.line,
+          column: current.column,
+          severity: current.severity,
+          raw: current.raw,
+          context: getSuggestion(current.message),
+        });
+      }
+      current = null;
+    };
+
+    for (const line of lines) {
+      const trimmed = line.trim();
+
+      const headerMatch = trimmed.match(HEADER_PATTERN);
+      if (headerMatch) {
+        flush();
+        current = {
+          severity: headerMatch[1] === "WARNING" ? "warning" : "error",
+          ...parseLocation(headerMatch[2] ?? ""),
+          code: "WEBPACK",
+          message: "",
+          raw: trimmed,
+        };
+        continue;
+      }
+
+      if (!current) continue;
+
+      // Blank line ends the block once a message has been found
+      if (!trimmed) {
+        if (current.message) flush();
+        continue;
+      }
+
+      // ts-loader gives a more precise location
+      const tslMatch = trimmed.match(TSL_PATTERN);
+      if (tslMatch) {
+        current.file = tslMatch[1] ?? current.file;
+        current.line = parseInt(tslMatch[2] ?? "0", 10);
+        current.column = parseInt(tslMatch[3] ?? "0", 10);
+        continue;
+      }
+
+      if (current.message || NOISE_PATTERNS.some((p) => p.test(trimmed))) continue;
+
+      const tsMatch = trimmed.match(TS_CODE_PATTERN);
+      if (tsMatch) {
+        current.code = tsMatch[1] ?? "WEBPACK";
+        current.message = tsMatch[2] ?? trimmed;
+      } else {
+        current.message = trimmed;
+      }
+      current.raw = `${current.raw} ${trimmed}`;
+    }
+
+    flush();
+
+    return errors;
+  },
+};
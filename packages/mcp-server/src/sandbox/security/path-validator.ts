@@ -15,6 +15,7 @@ import {
   brandAsSafePattern,
 } from "../branded-types.js";
 import { fileError, type FileError } from "../errors.js";
+import { isWithinDirectory, normalizeInputPath } from "../../utils/path-normalizer.js";
 
 /**
  * Blocked file patterns (sensitive files)
@@ -54,15 +55,14 @@ export function validatePath(
   workingDir: string
 ): PathValidation {
   try {
-    // Normalize and resolve path
-    const normalizedPath = path.normalize(filePath);
+    // Normalize (including Windows long-path and MSYS forms) and resolve path
+    const normalizedPath = path.normalize(normalizeInputPath(filePath));
     const resolvedPath = path.isAbsolute(normalizedPath)
       ? normalizedPath
       : path.resolve(workingDir, normalizedPath);
 
     // Check if path is within working directory
-    const relative = path.relative(workingDir, resolvedPath);
-    if (relative.startsWith("..") || path.isAbsolute(relative)) {
+    if (!isWithinDirectory(resolvedPath, workingDir)) {
       return {
         safe: false,
         error: `Path must be within working directory: ${workingDir}`,
@@ -72,8 +72,7 @@ export function validatePath(
     // Check for symlinks that might escape
     try {
       const realPath = fs.realpathSync(resolvedPath);
-      const realRelative = path.relative(workingDir, realPath);
-      if (realRelative.startsWith("..") || path.isAbsolute(realRelative)) {
+      if (!isWithinDirectory(realPath, fs.realpathSync(workingDir))) {
         return {
           safe: false,
           error: "Symlink escapes working directory",
@@ -5,6 +5,7 @@
  * Only core tools are loaded at startup to minimize token consumption.
  */
 
+import { randomBytes } from "crypto";
 import { Server } from "@modelcontextprotocol/sdk/server/index.js";
 import { StdioServerTransport } from "@modelcontextprotocol/sdk/server/stdio.js";
 import { CallToolRequestSchema, ListToolsRequestSchema } from "@modelcontextprotocol/sdk/types.js";
@@ -13,6 +14,14 @@ import { CallToolRequestSchema, ListToolsRequestSchema } from "@modelcontextprot
 import { createMiddlewareChain, type MiddlewareChain } from "./middleware/chain.js";
 import { createLoggingMiddleware } from "./middleware/logging.js";
 
+// Transports
+import {
+  DEFAULT_HTTP_PORT,
+  HTTP_ENDPOINT,
+  HTTP_HOST,
+  startHttpTransport,
+} from "./transports/http.js";
+
 // Tools
 import { createToolRegistry, type ToolRegistry } from "./tools/registry.js";
 import { getDynamicLoader, resetDynamicLoader } from "./tools/dynamic-loader.js";
@@ -32,6 +41,13 @@ export interface ServerConfig {
    * - "all": Load all tools at startup
    */
   mode?: LoadingMode;
+  /** Transport to serve on (default: "stdio") */
+  transport?: "stdio" | "http";
+  /** HTTP transport options; a random token is generated when none is given */
+  http?: {
+    port?: number;
+    token?: string;
+  };
 }
 
 export interface ServerInstance {
@@ -157,11 +173,23 @@ export async function createServer(config: ServerConfig = {}): Promise<ServerIns
 }
 
 /**
- * Run the MCP server on stdio transport
+ * Run the MCP server on stdio (default) or streamable HTTP transport
  */
 export async function runServer(config: ServerConfig = {}): Promise<void> {
   const { server } = await createServer(config);
 
+  if (config.transport === "http") {
+    const token = config.http?.token || randomBytes(24).toString("hex");
+    const port = config.http?.port ?? DEFAULT_HTTP_PORT;
+    await startHttpTransport(server, { token, port, verbose: config.verbose });
+
+    console.error(`Distill MCP Server running on http://${HTTP_HOST}:${port}${HTTP_ENDPOINT}`);
+    if (!config.http?.token) {
+      console.error(`Bearer token: ${token}`);
+    }
+    return;
+  }
+
   const transport = new StdioServerTransport();
 
   // Handle server close event
@@ -0,0 +1,153 @@
+/**
+ * Tests for External Summarizer Hook
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from "vitest";
+import * as fs from "fs";
+import * as http from "http";
+import * as os from "os";
+import * as path from "path";
+import type { AddressInfo } from "net";
+import {
+  DEFAULT_SUMMARIZER_TIMEOUT_MS,
+  getExternalSummarizerConfig,
+  runExternalSummarizer,
+  summarizeWithinBudget,
+} from "./external.js";
+
+const isWindows = process.platform === "win32";
+
+// Run node scripts through the shell portably
+const node = (script: string) => `"${process.execPath}" -e "${script}"`;
+
+function buildLog(templates: number): string {
+  return Array.from({ length: templates }, (_, i) => `event-${"x".repeat(i % 26)} ${i}`).join("\n");
+}
+
+describe("External Summarizer Hook", () => {
+  describe("getExternalSummarizerConfig", () => {
+    it("should be disabled unless explicitly enabled", () => {
+      expect(getExternalSummarizerConfig({ DISTILL_SUMMARIZER_COMMAND: "cat" })).toBeNull();
+      expect(getExternalSummarizerConfig({ DISTILL_EXTERNAL_SUMMARIZER: "true" })).toBeNull();
+    });
+
+    it("should read the command, url and timeout", () => {
+      expect(
+        getExternalSummarizerConfig({
+          DISTILL_EXTERNAL_SUMMARIZER: "true",
+          DISTILL_SUMMARIZER_URL: "http://127.0.0.1:8080/summarize",
+          DISTILL_SUMMARIZER_TIMEOUT_MS: "2500",
+        })
+      ).toEqual({ command: undefined, url: "http://127.0.0.1:8080/summarize", timeoutMs: 2500 });
+
+      expect(
+        getExternalSummarizerConfig({
+          DISTILL_EXTERNAL_SUMMARIZER: "true",
+          DISTILL_SUMMARIZER_COMMAND: "cat",
+          DISTILL_SUMMARIZER_TIMEOUT_MS: "soon",
+        })?.timeoutMs
+      ).toBe(DEFAULT_SUMMARIZER_TIMEOUT_MS);
+    });
+  });
+
+  describe("runExternalSummarizer with a command", () => {
+    it("should pass the text on stdin and the budget in the environment", async () => {
+      const command = node(
+        "process.stdin.on('data',()=>{});process.stdin.on('end',()=>console.log('budget '+process.env.DISTILL_MAX_TOKENS))"
+      );
+      const result = await runExternalSummarizer("some text", 50, { command, timeoutMs: 5000 });
+      expect(result).toMatchObject({ text: "budget 50", method: "external" });
+    });
+
+    it("should trim output over budget", async () => {
+      const command = node("for(let i=0;i<200;i++)console.log('summary line '+i)");
+      const result = await runExternalSummarizer("text", 40, { command, timeoutMs: 5000 });
+      expect(result!.tokens).toBeLessThanOrEqual(40);
+    });
+
+    it("should return null on timeout, failure or empty output", async () => {
+      const slow = node("setTimeout(()=>{},5000)");
+      expect(await runExternalSummarizer("text", 50, { command: slow, timeoutMs: 200 })).toBeNull();
+      expect(
+        await runExternalSummarizer("text", 50, { command: node("process.exit(1)"), timeoutMs: 5000 })
+      ).toBeNull();
+      expect(
+        await runExternalSummarizer("text", 50, { command: node("0"), timeoutMs: 5000 })
+      ).toBeNull();
+    });
+
+    it.skipIf(isWindows)("should kill processes started by the command on timeout", async () => {
+      const marker = path.join(os.tmpdir(), `summarizer-orphan-${process.pid}`);
+      fs.rmSync(marker, { force: true });
+      // "; exit 0" keeps the shell as the parent of node instead of exec-ing it
+      const script = `setTimeout(()=>require('fs').writeFileSync('${marker}','x'),1000)`;
+      const command = `${node(script)}; exit 0`;
+
+      expect(await runExternalSummarizer("text", 50, { command, timeoutMs: 200 })).toBeNull();
+      await new Promise((resolve) => setTimeout(resolve, 1500));
+      expect(fs.existsSync(marker)).toBe(false);
+    });
+  });
+
+  describe("runExternalSummarizer with an endpoint", () => {
+    let server: http.Server;
+    let url: string;
+
+    beforeAll(async () => {
+      server = http.createServer((req, res) => {
+        let body = "";
+        req.on("data", (chunk) => (body += chunk));
+        req.on("end", () => {
+          const { maxTokens } = JSON.parse(body) as { maxTokens: number };
+          if (req.url === "/fail") {
+            res.writeHead(500).end();
+          } else {
+            res.writeHead(200, { "Content-Type": "application/json" });
+            res.end(JSON.stringify({ summary: `summarized within ${maxTokens}` }));
+          }
+        });
+      });
+      await new Promise<void>((resolve) => server.listen(0, "127.0.0.1", resolve));
+      url = `http://127.0.0.1:${(server.address() as AddressInfo).port}`;
+    });
+
+    afterAll(() => {
+      server.close();
+    });
+
+    it("should post the text and read the summary", async () => {
+      const result = await runExternalSummarizer("text", 80, { url: `${url}/ok`, timeoutMs: 5000 });
+      expect(result?.text).toBe("summarized within 80");
+    });
+
+    it("should return null on HTTP errors", async () => {
+      expect(await runExternalSummarizer("text", 80, { url: `${url}/fail`, timeoutMs: 5000 })).toBeNull();
+    });
+  });
+
+  describe("summarizeWithinBudget", () => {
+    const command = node("console.log('external summary')");
+
+    it("should use the extractive summary when it keeps every template", async () => {
+      const result = await summarizeWithinBudget(buildLog(3), 200, { command, timeoutMs: 5000 });
+      expect(result.method).toBe("extractive");
+    });
+
+    it("should call the external summarizer when templates are dropped", async () => {
+      const result = await summarizeWithinBudget(buildLog(26), 40, { command, timeoutMs: 5000 });
+      expect(result).toMatchObject({ text: "external summary", method: "external" });
+    });
+
+    it("should fall back to the extractive summary when the hook fails", async () => {
+      const failing = { command: node("process.exit(1)"), timeoutMs: 5000 };
+      const result = await summarizeWithinBudget(buildLog(26), 40, failing);
+      expect(result.method).toBe("extractive");
+      expect(result.tokens).toBeLessThanOrEqual(40);
+    });
+
+    it("should stay extractive when no summarizer is configured", async () => {
+      const result = await summarizeWithinBudget(buildLog(26), 40, null);
+      expect(result.method).toBe("extractive");
+    });
+  });
+});
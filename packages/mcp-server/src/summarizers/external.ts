@@ -0,0 +1,198 @@
+/**
+ * External Summarizer Hook
+ *
+ * Optional model-backed summarization through a user-provided command or
+ * HTTP endpoint (e.g. a local small model). Strictly opt-in:
+ *
+ *   DISTILL_EXTERNAL_SUMMARIZER=true
+ *   DISTILL_SUMMARIZER_COMMAND="ollama run llama3.2 'Summarize this log:'"
+ *   DISTILL_SUMMARIZER_URL=http://127.0.0.1:8080/summarize
+ *   DISTILL_SUMMARIZER_TIMEOUT_MS=10000
+ *
+ * The command receives the text on stdin and DISTILL_MAX_TOKENS in its
+ * environment, and prints the summary on stdout. The endpoint receives
+ * a JSON POST {text, maxTokens} and answers with {summary} or plain text.
+ * Output over budget is trimmed extractively; any failure returns null so
+ * callers fall back to the extractive summary.
+ */
+
+import { spawn, type ChildProcess } from "child_process";
+import { countTokens } from "../utils/token-counter.js";
+import { summarizeExtractive } from "./extractive.js";
+
+export const DEFAULT_SUMMARIZER_TIMEOUT_MS = 10_000;
+// Output collected from the command/endpoint before giving up on the rest
+const MAX_OUTPUT_CHARS = 256 * 1024;
+
+export interface ExternalSummarizerConfig {
+  /** Shell command, preferred over url when both are set */
+  command?: string;
+  url?: string;
+  timeoutMs: number;
+}
+
+export interface BudgetedSummary {
+  text: string;
+  tokens: number;
+  method: "extractive" | "external";
+}
+
+/**
+ * Read the external summarizer configuration; null unless enabled
+ */
+export function getExternalSummarizerConfig(
+  env: NodeJS.ProcessEnv = process.env
+): ExternalSummarizerConfig | null {
+  if (env.DISTILL_EXTERNAL_SUMMARIZER !== "true") return null;
+
+  const command = env.DISTILL_SUMMARIZER_COMMAND?.trim() || undefined;
+  const url = env.DISTILL_SUMMARIZER_URL?.trim() || undefined;
+  if (!command && !url) return null;
+
+  const timeout = Number(env.DISTILL_SUMMARIZER_TIMEOUT_MS);
+  return {
+    command,
+    url,
+    timeoutMs: Number.isFinite(timeout) && timeout > 0 ? timeout : DEFAULT_SUMMARIZER_TIMEOUT_MS,
+  };
+}
+
+/**
+ * Kill a shell command with everything it started
+ * The shell is only the parent: killing it alone would leave the
+ * actual summarizer running, so the whole process group is killed.
+ */
+function killCommand(child: ChildProcess): void {
+  if (child.pid === undefined) return;
+  try {
+    if (process.platform === "win32") {
+      spawn("taskkill", ["/pid", String(child.pid), "/T", "/F"], { stdio: "ignore" });
+    } else {
+      process.kill(-child.pid, "SIGKILL");
+    }
+  } catch {
+    // Already exited
+  }
+}
+
+/**
+ * Run a summarizer command with the text on stdin
+ */
+function runCommand(
+  command: string,
+  text: string,
+  maxTokens: number,
+  timeoutMs: number
+): Promise<string | null> {
+  return new Promise((resolve) => {
+    const child = spawn(command, {
+      shell: true,
+      // Own process group, so a timeout can kill the command's children too
+      detached: process.platform !== "win32",
+      stdio: ["pipe", "pipe", "ignore"],
+      env: { ...process.env, DISTILL_MAX_TOKENS: String(maxTokens) },
+    });
+
+    let output = "";
+    let settled = false;
+    const finish = (result: string | null) => {
+      if (settled) return;
+      settled = true;
+      clearTimeout(timer);
+      resolve(result);
+    };
+
+    const timer = setTimeout(() => {
+      killCommand(child);
+      finish(null);
+    }, timeoutMs);
+
+    child.stdout.setEncoding("utf-8");
+    child.stdout.on("data", (chunk: string) => {
+      output += chunk;
+      if (output.length > MAX_OUTPUT_CHARS) {
+        killCommand(child);
+        finish(output.slice(0, MAX_OUTPUT_CHARS));
+      }
+    });
+    child.on("error", () => finish(null));
+    child.on("close", (code) => finish(code === 0 ? output : null));
+
+    // The command may exit without reading all of its input
+    child.stdin.on("error", () => {});
+    child.stdin.end(text);
+  });
+}
+
+/**
+ * POST the text to a summarizer endpoint
+ */
+async function runHttp(
+  url: string,
+  text: string,
+  maxTokens: number,
+  timeoutMs: number
+): Promise<string | null> {
+  try {
+    const response = await fetch(url, {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({ text, maxTokens }),
+      signal: AbortSignal.timeout(timeoutMs),
+    });
+    if (!response.ok) return null;
+
+    const body = (await response.text()).slice(0, MAX_OUTPUT_CHARS);
+    if (!response.headers.get("content-type")?.includes("application/json")) return body;
+
+    const parsed = JSON.parse(body) as { summary?: unknown };
+    return typeof parsed.summary === "string" ? parsed.summary : null;
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Summarize with the external summarizer; null on failure or empty output
+ */
+export async function runExternalSummarizer(
+  text: string,
+  maxTokens: number,
+  config: ExternalSummarizerConfig
+): Promise<BudgetedSummary | null> {
+  const output = config.command
+    ? await runCommand(config.command, text, maxTokens, config.timeoutMs)
+    : await runHttp(config.url!, text, maxTokens, config.timeoutMs);
+
+  const summary = output?.trim();
+  if (!summary) return null;
+
+  // Models do not reliably respect the budget
+  const tokens = countTokens(summary);
+  if (tokens <= maxTokens) return { text: summary, tokens, method: "external" };
+
+  const trimmed = summarizeExtractive(summary, { maxTokens });
+  return { text: trimmed.text, tokens: trimmed.tokens, method: "external" };
+}
+
+/**
+ * Summarize text under a token budget
+ *
+ * Uses the extractive summary when it keeps a line of every template,
+ * and otherwise tries the external summarizer if one is configured.
+ */
+export async function summarizeWithinBudget(
+  text: string,
+  maxTokens: number,
+  config: ExternalSummarizerConfig | null = getExternalSummarizerConfig()
+): Promise<BudgetedSummary> {
+  const extractive = summarizeExtractive(text, { maxTokens });
+  const fallback: BudgetedSummary = {
+    text: extractive.text,
+    tokens: extractive.tokens,
+    method: "extractive",
+  };
+
+  if (!config || extractive.keptTemplates >= extractive.templates) return fallback;
+  return (await runExternalSummarizer(text, maxTokens, config)) ?? fallback;
+}
@@ -0,0 +1,83 @@
+/**
+ * Tests for Extractive Summarizer
+ */
+
+import { describe, it, expect } from "vitest";
+import { lineTemplate, summarizeExtractive } from "./extractive.js";
+import { countTokens } from "../utils/token-counter.js";
+
+function buildLog(): string {
+  const lines: string[] = ["Starting server v1.2.3", "Loaded config from /etc/app.yaml"];
+  for (let i = 0; i < 300; i++) {
+    lines.push(`2024-01-15 10:30:${String(i % 60).padStart(2, "0")} INFO request ${i} served in ${i}ms`);
+    if (i === 150) {
+      lines.push("2024-01-15 10:32:30 ERROR Database connection lost");
+      lines.push("    at Pool.connect (src/db/pool.ts:42:11)");
+      lines.push("    at Server.handle (src/server.ts:88:5)");
+    }
+    if (i === 200) lines.push("2024-01-15 10:33:20 WARN Slow query detected");
+  }
+  lines.push("Shutting down", "Goodbye");
+  return lines.join("\n");
+}
+
+describe("Extractive Summarizer", () => {
+  describe("lineTemplate", () => {
+    it("should mask numbers, hex values and quoted strings", () => {
+      expect(lineTemplate("request 42 took 1.5ms at 0xdeadbeef")).toBe(
+        "request <N> took <N>ms at <HEX>"
+      );
+      expect(lineTemplate("user 'alice' logged in")).toBe(lineTemplate("user 'bob' logged in"));
+    });
+  });
+
+  describe("summarizeExtractive", () => {
+    it("should return text unchanged when it fits", () => {
+      const result = summarizeExtractive("line one\nline two\n", { maxTokens: 100 });
+      expect(result.text).toBe("line one\nline two");
+      expect(result.keptLines).toBe(2);
+    });
+
+    it("should stay within the token budget", () => {
+      for (const maxTokens of [40, 100, 300]) {
+        const result = summarizeExtractive(buildLog(), { maxTokens });
+        expect(result.tokens).toBeLessThanOrEqual(maxTokens);
+        expect(countTokens(result.text)).toBe(result.tokens);
+      }
+    });
+
+    it("should keep errors with their stack lines first", () => {
+      const result = summarizeExtractive(buildLog(), { maxTokens: 60 });
+      expect(result.text).toContain("ERROR Database connection lost");
+      expect(result.text).toContain("at Pool.connect");
+    });
+
+    it("should keep first and last lines, warnings and one line per template", () => {
+      const result = summarizeExtractive(buildLog(), { maxTokens: 400 });
+      const lines = result.text.split("\n");
+
+      expect(lines[0]).toBe("Starting server v1.2.3");
+      expect(lines[lines.length - 1]).toBe("Goodbye");
+      expect(result.text).toContain("WARN Slow query detected");
+      expect(result.text).toContain("INFO request 0 served in 0ms (×300)");
+      expect(result.text).not.toContain("INFO request 7 served");
+      expect(result.keptTemplates).toBe(result.templates);
+    });
+
+    it("should mark omitted spans and preserve order", () => {
+      const result = summarizeExtractive(buildLog(), { maxTokens: 400 });
+      expect(result.text).toMatch(/\.\.\. \d+ lines omitted/);
+      expect(result.text.indexOf("Database connection lost")).toBeLessThan(
+        result.text.indexOf("Slow query detected")
+      );
+      expect(result.totalLines).toBe(308);
+    });
+
+    it("should be deterministic", () => {
+      const log = buildLog();
+      expect(summarizeExtractive(log, { maxTokens: 120 })).toEqual(
+        summarizeExtractive(log, { maxTokens: 120 })
+      );
+    });
+  });
+});
@@ -0,0 +1,211 @@
+/**
+ * Extractive Summarizer
+ *
+ * Deterministic, model-free summarization of logs and long text under a
+ * token budget. Lines are picked by priority (errors with their stack
+ * lines, warnings, first/last lines, then one line per unique template,
+ * rarest first) and emitted in their original order with omitted spans
+ * marked.
+ */
+
+import { normalizeErrorLine } from "../utils/error-normalizer.js";
+import { parseLogLevel } from "../utils/log-parser.js";
+import { countTokens } from "../utils/token-counter.js";
+
+export interface ExtractiveOptions {
+  /** Token budget for the summary text */
+  maxTokens: number;
+  /** Lines kept from each end of the text when they fit (default: 5) */
+  edgeLines?: number;
+}
+
+export interface ExtractiveSummary {
+  /** Selected lines in original order, omitted spans marked */
+  text: string;
+  tokens: number;
+  totalLines: number;
+  keptLines: number;
+  /** Distinct line templates in the input */
+  templates: number;
+  /** Distinct line templates with at least one kept line */
+  keptTemplates: number;
+}
+
+// Lower priorities are selected first
+const PRIORITY_ERROR = 0;
+const PRIORITY_WARNING = 1;
+const PRIORITY_EDGE = 2;
+const PRIORITY_TEMPLATE = 3;
+
+const MAX_LINE_CHARS = 400;
+// Indented lines kept after an error (stack frames, details)
+const MAX_CONTINUATION_LINES = 3;
+// Upper bound for one "... N lines omitted" marker
+const GAP_TOKENS = 8;
+
+interface Candidate {
+  index: number;
+  priority: number;
+  /** Tie-break within a priority, lower first */
+  rank: number;
+  template: string;
+  /** Occurrences of the line's template */
+  frequency: number;
+}
+
+/**
+ * Reduce a line to its template by masking variable parts
+ */
+export function lineTemplate(line: string): string {
+  return normalizeErrorLine(line)
+    .replace(/\b0x[0-9a-f]+\b/gi, "<HEX>")
+    .replace(/\d+(?:\.\d+)?/g, "<N>");
+}
+
+function truncateLine(line: string): string {
+  return line.length > MAX_LINE_CHARS ? `${line.slice(0, MAX_LINE_CHARS - 3)}...` : line;
+}
+
+function omittedMarker(count: number): string {
+  return `... ${count} line${count === 1 ? "" : "s"} omitted`;
+}
+
+/**
+ * Rank lines for selection
+ */
+function rankLines(lines: string[], templates: string[], edgeLines: number): Candidate[] {
+  const frequency = new Map<string, number>();
+  for (const template of templates) {
+    if (template) frequency.set(template, (frequency.get(template) ?? 0) + 1);
+  }
+
+  const candidates: Candidate[] = [];
+  const seen = new Set<string>();
+  let continuationLeft = 0;
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i]!;
+    const template = templates[i]!;
+    if (!template) {
+      continuationLeft = 0;
+      continue;
+    }
+
+    const isEdge = i < edgeLines || i >= lines.length - edgeLines;
+    const isContinuation = continuationLeft > 0 && /^\s/.test(line);
+    const level = parseLogLevel(line);
+    continuationLeft = isContinuation ? continuationLeft - 1 : 0;
+
+    const isNew = !seen.has(template);
+    const count = frequency.get(template) ?? 1;
+    seen.add(template);
+
+    let priority: number | null = null;
+    let rank = 0;
+    if (isContinuation) {
+      priority = PRIORITY_ERROR;
+    } else if (level === "error" && isNew) {
+      priority = PRIORITY_ERROR;
+      continuationLeft = MAX_CONTINUATION_LINES;
+    } else if (level === "warning" && isNew) {
+      priority = PRIORITY_WARNING;
+    } else if (isEdge) {
+      // Alternate between both ends
+      priority = PRIORITY_EDGE;
+      rank = Math.min(i, lines.length - 1 - i);
+    } else if (isNew) {
+      // Rare templates carry more information than frequent ones
+      priority = PRIORITY_TEMPLATE;
+      rank = count;
+    }
+
+    if (priority !== null) {
+      candidates.push({ index: i, priority, rank, template, frequency: count });
+    }
+  }
+
+  return candidates.sort((a, b) => a.priority - b.priority || a.rank - b.rank || a.index - b.index);
+}
+
+/**
+ * Summarize text by selecting lines under a token budget
+ */
+export function summarizeExtractive(text: string, options: ExtractiveOptions): ExtractiveSummary {
+  const lines = text.replace(/\r\n/g, "\n").split("\n");
+  while (lines.length > 0 && !lines[lines.length - 1]!.trim()) lines.pop();
+
+  const templates = lines.map((line) => (line.trim() ? lineTemplate(line) : ""));
+  const templateCount = new Set(templates.filter(Boolean)).size;
+
+  const full = lines.join("\n");
+  const fullTokens = countTokens(full);
+  if (fullTokens <= options.maxTokens) {
+    return {
+      text: full,
+      tokens: fullTokens,
+      totalLines: lines.length,
+      keptLines: lines.length,
+      templates: templateCount,
+      keptTemplates: templateCount,
+    };
+  }
+
+  const candidates = rankLines(lines, templates, options.edgeLines ?? 5);
+  const rendered = new Map<number, string>();
+  const selected: number[] = [];
+  const counted = new Set<string>();
+  // Reserve room for a trailing marker; each line also pays for the marker before it
+  let budget = options.maxTokens - GAP_TOKENS;
+
+  for (const candidate of candidates) {
+    if (budget < GAP_TOKENS + 2) break;
+
+    const { index, template, frequency } = candidate;
+    const line = truncateLine(lines[index]!);
+    // Repeat counts are shown once per template
+    const showCount = frequency > 1 && !counted.has(template);
+    const output = showCount ? `${line} (×${frequency})` : line;
+    const cost = countTokens(output) + 1 + GAP_TOKENS;
+    if (cost > budget) continue;
+
+    if (showCount) counted.add(template);
+    rendered.set(index, output);
+    selected.push(index);
+    budget -= cost;
+  }
+
+  const render = (): string => {
+    const out: string[] = [];
+    let omitted = 0;
+    for (let i = 0; i < lines.length; i++) {
+      const line = rendered.get(i);
+      if (line === undefined) {
+        omitted++;
+        continue;
+      }
+      if (omitted > 0) out.push(omittedMarker(omitted));
+      out.push(line);
+      omitted = 0;
+    }
+    if (omitted > 0) out.push(omittedMarker(omitted));
+    return out.join("\n");
+  };
+
+  // Token counts of joined lines can drift from the per-line estimate
+  let summary = render();
+  let tokens = countTokens(summary);
+  while (tokens > options.maxTokens && selected.length > 0) {
+    rendered.delete(selected.pop()!);
+    summary = render();
+    tokens = countTokens(summary);
+  }
+
+  return {
+    text: summary,
+    tokens,
+    totalLines: lines.length,
+    keptLines: rendered.size,
+    templates: templateCount,
+    keptTemplates: new Set([...rendered.keys()].map((i) => templates[i])).size,
+  };
+}
@@ -16,6 +16,8 @@ export * from "./scoring.js";
 export * from "./pattern-extraction.js";
 export * from "./clustering.js";
 export * from "./hierarchical.js";
+export * from "./extractive.js";
+export * from "./external.js";
 
 import type { Summarizer, LogType } from "./types.js";
 import { serverLogsSummarizer } from "./server-logs.js";
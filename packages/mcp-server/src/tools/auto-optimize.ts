@@ -5,18 +5,23 @@
  * the appropriate optimization automatically.
  */
 
+import { z } from "zod";
+
 import type { ToolDefinition } from "./registry.js";
 
 import { detectContentType } from "../utils/content-detector.js";
 import type { ContentType } from "../compressors/types.js";
 import { compressContent } from "../compressors/index.js";
-import { getSummarizer } from "../summarizers/index.js";
+import { getSummarizer, summarizeWithinBudget } from "../summarizers/index.js";
 import { analyzeBuildOutput } from "../parsers/index.js";
 import { groupBySignature, formatGroups, calculateStats } from "../utils/signature-grouper.js";
 import { countTokens } from "../utils/token-counter.js";
 
 type OutputFormat = "plain" | "markdown";
 
+// Same bounds as summarize_logs
+const maxTokensSchema = z.number().int().min(50).max(50000).optional();
+
 // Input schema with semantic descriptions for better LLM understanding
 const autoOptimizeSchema = {
   type: "object" as const,
@@ -41,6 +46,10 @@ const autoOptimizeSchema = {
       description: "Output format",
       default: "plain",
     },
+    maxTokens: {
+      type: "number",
+      description: "Max output tokens",
+    },
   },
   required: ["content"],
 };
@@ -82,6 +91,7 @@ interface AutoOptimizeArgs {
   hint?: "build" | "logs" | "errors" | "code" | "auto";
   aggressive?: boolean;
   format?: OutputFormat;
+  maxTokens?: number;
 }
 
 interface OptimizationResult {
@@ -209,6 +219,24 @@ function optimizeErrors(content: string, format: OutputFormat = "plain"): Optimi
   };
 }
 
+async function optimizeWithinBudget(
+  content: string,
+  maxTokens: number,
+  detectedType: string
+): Promise<OptimizationResult> {
+  const originalTokens = countTokens(content);
+  const summary = await summarizeWithinBudget(content, maxTokens);
+
+  return {
+    optimizedContent: summary.text,
+    detectedType,
+    originalTokens,
+    optimizedTokens: summary.tokens,
+    savingsPercent: Math.round((1 - summary.tokens / originalTokens) * 100),
+    method: summary.method,
+  };
+}
+
 function optimizeGeneric(content: string, aggressive: boolean): OptimizationResult {
   const originalTokens = countTokens(content);
   const result = compressContent(content, {
@@ -231,6 +259,20 @@ async function autoOptimize(
   const { content, hint = "auto", aggressive = false, format = "plain" } = args;
   const md = format === "markdown";
 
+  const budget = maxTokensSchema.safeParse(args.maxTokens);
+  if (!budget.success) {
+    return {
+      content: [
+        {
+          type: "text",
+          text: `Invalid input: maxTokens ${budget.error.errors.map((e) => e.message).join(", ")}`,
+        },
+      ],
+      isError: true,
+    };
+  }
+  const maxTokens = budget.data;
+
   // Minimum threshold for optimization (500 chars ~ 125 tokens)
   if (content.length < 500) {
     const msg = md
@@ -266,6 +308,11 @@ async function autoOptimize(
     }
   }
 
+  // Enforce the token budget (extractive, or the opt-in external summarizer)
+  if (maxTokens !== undefined && result.optimizedTokens > maxTokens) {
+    result = await optimizeWithinBudget(content, maxTokens, result.detectedType);
+  }
+
   // Format output - minimal header to save tokens
   const stats = `[${result.detectedType}] ${result.originalTokens}→${result.optimizedTokens} tokens (-${result.savingsPercent}%)`;
   const output = `${stats}\n${result.optimizedContent}`;
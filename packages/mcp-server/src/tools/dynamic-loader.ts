@@ -144,6 +144,27 @@ export const TOOL_CATALOG: ToolMetadata[] = [
     description: "Manage parsed file cache",
     loader: async () => (await import("./smart-cache-tool.js")).smartCacheTool,
   },
+  {
+    name: "smart_file_diff",
+    category: "code",
+    keywords: ["diff", "changed", "modified", "file", "reread", "since", "edit"],
+    description: "Show only what changed in a file since it was last read",
+    loader: async () => (await import("./smart-file-diff.js")).smartFileDiffTool,
+  },
+  {
+    name: "project_map",
+    category: "code",
+    keywords: ["project", "map", "tree", "overview", "structure", "repo", "layout", "orient"],
+    description: "Token-budgeted directory tree with key file summaries",
+    loader: async () => (await import("./project-map.js")).projectMapTool,
+  },
+  {
+    name: "grep_context",
+    category: "code",
+    keywords: ["grep", "search", "find", "regex", "match", "occurrences", "usages", "context"],
+    description: "Regex search with context lines, capped to a token budget",
+    loader: async () => (await import("./grep-context.js")).grepContextTool,
+  },
 
   // Pipeline category
   {
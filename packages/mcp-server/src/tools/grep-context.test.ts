@@ -0,0 +1,152 @@
+/**
+ * Grep Context Tool Tests
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from "vitest";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import {
+  executeGrepContext,
+  formatGrepResults,
+  grepFiles,
+  hasNestedQuantifier,
+} from "./grep-context.js";
+import { countTokens } from "../utils/token-counter.js";
+
+const OPTIONS = { context: 1, maxTokens: 2000, maxPerFile: 5 };
+
+describe("grep_context tool", () => {
+  let rootDir: string;
+
+  beforeAll(() => {
+    rootDir = fs.mkdtempSync(path.join(os.tmpdir(), "grep-context-"));
+    const write = (rel: string, content: string | Buffer) => {
+      fs.mkdirSync(path.dirname(path.join(rootDir, rel)), { recursive: true });
+      fs.writeFileSync(path.join(rootDir, rel), content);
+    };
+
+    write("src/a.ts", ["import x from 'y';", "", "const needle = 1;", "const other = 2;", ""].join("\n"));
+    write("src/b.js", Array.from({ length: 20 }, (_, i) => `line ${i} needle`).join("\r\n"));
+    write("node_modules/dep/index.js", "const needle = 3;");
+    write(".hidden/c.ts", "needle");
+    write(".gitignore", "generated/\n");
+    write("generated/api.ts", "const needle = 4;");
+    write("src/.ctxoptignore", "*.fixture.ts\n");
+    write("src/data.fixture.ts", "const needle = 5;");
+    write(".ssh/id_rsa", "needle");
+    write("config/prod.env", "NEEDLE_TOKEN=needle");
+    write("data.bin", Buffer.from([0x6e, 0x65, 0x65, 0x64, 0x6c, 0x65, 0x00, 0x01]));
+  });
+
+  afterAll(() => {
+    fs.rmSync(rootDir, { recursive: true, force: true });
+  });
+
+  describe("search", () => {
+    it("should skip dependencies, hidden directories, binary and ignored files", () => {
+      const summary = grepFiles(rootDir, /needle/, OPTIONS);
+      expect(summary.files.map((f) => f.relPath)).toEqual(["src/a.ts", "src/b.js"]);
+      expect(summary.totalMatches).toBe(21);
+    });
+
+    it("should never search sensitive files", () => {
+      const summary = grepFiles(rootDir, /needle/i, OPTIONS);
+      expect(summary.files.map((f) => f.relPath)).not.toContain("config/prod.env");
+      expect(grepFiles(path.join(rootDir, ".ssh"), /needle/, OPTIONS).files).toHaveLength(0);
+    });
+
+    it("should filter files by glob", () => {
+      const search = (glob: string) =>
+        grepFiles(rootDir, /needle/, { ...OPTIONS, glob }).files.map((f) => f.relPath);
+      expect(search("*.ts")).toEqual(["src/a.ts"]);
+      expect(search("src/**/*.{js,jsx}")).toEqual(["src/b.js"]);
+    });
+
+    it("should reject an invalid glob", async () => {
+      const result = await executeGrepContext({ pattern: "needle", glob: "[z-a].ts" });
+      expect(result.isError).toBe(true);
+      expect(result.content[0]?.text).toBe("Invalid glob: [z-a].ts");
+    });
+
+    it("should reject patterns with nested quantifiers", async () => {
+      expect(hasNestedQuantifier("(a+)+$")).toBe(true);
+      expect(hasNestedQuantifier("(\\w*){2,}")).toBe(true);
+      expect(hasNestedQuantifier("(foo|bar)+\\(x+\\)")).toBe(false);
+
+      const result = await executeGrepContext({ pattern: "(a+)+$" });
+      expect(result.isError).toBe(true);
+      expect((await executeGrepContext({ pattern: "(a+)+$", literal: true })).isError).toBeFalsy();
+    });
+
+    it("should reject overlong patterns", async () => {
+      const result = await executeGrepContext({ pattern: "a".repeat(501) });
+      expect(result.isError).toBe(true);
+    });
+
+    it("should keep only the context of shown matches", () => {
+      const file = grepFiles(rootDir, /needle/, { ...OPTIONS, glob: "*.js" }).files[0]!;
+      expect(file.matchCount).toBe(20);
+      expect(file.matchLines).toEqual([0, 1, 2, 3, 4]);
+      expect([...file.lines.keys()]).toEqual([0, 1, 2, 3, 4, 5]);
+    });
+
+    it("should search a single file", () => {
+      const summary = grepFiles(path.join(rootDir, "src/a.ts"), /needle/, OPTIONS);
+      expect(summary.files).toHaveLength(1);
+      expect(summary.files[0]!.matchLines).toEqual([2]);
+    });
+  });
+
+  describe("formatting", () => {
+    it("should show matches with context lines", () => {
+      const summary = grepFiles(rootDir, /needle/, { ...OPTIONS, glob: "*.ts" });
+      const text = formatGrepResults(summary, "needle", OPTIONS);
+      expect(text).toContain("[grep] /needle/ 1 matches in 1 files");
+      expect(text).toContain("2-\n3:const needle = 1;\n4-const other = 2;");
+    });
+
+    it("should cap matches per file", () => {
+      const summary = grepFiles(rootDir, /needle/, { ...OPTIONS, glob: "*.js" });
+      const text = formatGrepResults(summary, "needle", OPTIONS);
+      expect(text).toContain("src/b.js (20 matches, showing 5)");
+      expect(text).toContain("5:line 4 needle");
+      expect(text).not.toContain("6:line 5 needle");
+    });
+
+    it("should stay within the token budget", () => {
+      const options = { ...OPTIONS, maxPerFile: 100, maxTokens: 100 };
+      const text = formatGrepResults(grepFiles(rootDir, /needle/, options), "needle", options);
+      expect(text).toContain("showing");
+      expect(text.length).toBeLessThan(100 * 8);
+    });
+
+    it("should fit the header and the list of files not shown in the budget", () => {
+      const files = Array.from({ length: 30 }, (_, i) => ({
+        relPath: `packages/module-${i}/src/components/deeply/nested/handler-${i}.ts`,
+        matchCount: 10,
+        matchLines: Array.from({ length: 5 }, (_, j) => j),
+        lines: new Map(
+          Array.from({ length: 6 }, (_, j) => [j, `const needle${j} = "${"x".repeat(40)}";`])
+        ),
+      }));
+      const summary = {
+        files,
+        totalMatches: 300,
+        filesSearched: 30,
+        truncated: false,
+        timedOut: false,
+      };
+
+      const text = formatGrepResults(summary, "needle", { ...OPTIONS, maxTokens: 300 });
+      expect(text).toContain("Not shown: ");
+      expect(text).toMatch(/, \+\d+ more$/);
+      expect(countTokens(text)).toBeLessThanOrEqual(300);
+    });
+
+    it("should report no matches", () => {
+      const text = formatGrepResults(grepFiles(rootDir, /missing/, OPTIONS), "missing", OPTIONS);
+      expect(text).toContain("no matches");
+    });
+  });
+});
@@ -0,0 +1,434 @@
+/**
+ * Grep Context Tool
+ *
+ * Regex search across the working directory that returns matches with
+ * surrounding context lines, capped per file and truncated to a token
+ * budget. Token-efficient replacement for running raw grep in a shell.
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { z } from "zod";
+
+import type { ToolDefinition } from "./registry.js";
+import { isBlockedPath, validatePath } from "../sandbox/security/path-validator.js";
+import { isExcludedDir, isExcludedFile } from "../utils/file-filters.js";
+import { IgnoreMatcher, globToRegExp } from "../utils/ignore-rules.js";
+import { decodeText } from "../utils/text-encoding.js";
+import { countTokens } from "../utils/token-counter.js";
+
+const MAX_FILES = 5000;
+const MAX_FILE_SIZE = 1024 * 1024; // 1MB
+const MAX_LINE_LENGTH = 240;
+const MAX_NOT_SHOWN = 10;
+const MAX_PATTERN_LENGTH = 500;
+const SEARCH_TIME_LIMIT_MS = 10_000;
+
+// A quantified group that itself contains a quantifier, e.g. (a+)+ or (\w*){2,};
+// such patterns can backtrack exponentially on a single line
+const NESTED_QUANTIFIER =
+  /\((?:[^()\\]|\\.)*(?:[*+]|\{\d+,\d*\})(?:[^()\\]|\\.)*\)(?:[*+]|\{\d+,\d*\})/;
+
+// Minimal schema
+export const grepContextSchema = {
+  type: "object" as const,
+  properties: {
+    pattern: { type: "string", description: "Regex to search for" },
+    path: { type: "string" },
+    glob: { type: "string", description: "e.g. *.ts, src/**/*.{js,jsx}" },
+    ignoreCase: { type: "boolean" },
+    literal: { type: "boolean" },
+    context: { type: "number" },
+    maxTokens: { type: "number" },
+    maxPerFile: { type: "number" },
+  },
+  required: ["pattern"],
+};
+
+const inputSchema = z.object({
+  pattern: z.string().min(1).max(MAX_PATTERN_LENGTH),
+  path: z.string().optional().default("."),
+  glob: z.string().optional(),
+  ignoreCase: z.boolean().optional().default(false),
+  literal: z.boolean().optional().default(false),
+  context: z.number().int().min(0).max(10).optional().default(2),
+  maxTokens: z.number().int().min(100).max(50000).optional().default(2000),
+  maxPerFile: z.number().int().min(1).max(100).optional().default(5),
+});
+
+export interface SearchOptions {
+  /** Only search paths matching this glob */
+  glob?: string;
+  /** Lines of context around each match */
+  context: number;
+  /** Matches shown per file; the rest are only counted */
+  maxPerFile: number;
+}
+
+export interface GrepOptions extends SearchOptions {
+  /** Token budget for the formatted result */
+  maxTokens: number;
+}
+
+export interface FileMatches {
+  relPath: string;
+  /** Number of matching lines */
+  matchCount: number;
+  /** 0-based indices of the first maxPerFile matching lines */
+  matchLines: number[];
+  /** Those matches and their context lines (clipped), by 0-based index */
+  lines: Map<number, string>;
+}
+
+export interface GrepSummary {
+  files: FileMatches[];
+  totalMatches: number;
+  filesSearched: number;
+  /** Search stopped at MAX_FILES */
+  truncated: boolean;
+  /** Search stopped at SEARCH_TIME_LIMIT_MS */
+  timedOut: boolean;
+}
+
+/**
+ * Collect candidate files under a directory, sorted by path
+ */
+function collectFiles(
+  rootDir: string,
+  glob: string | undefined
+): { files: string[]; truncated: boolean } {
+  const files: string[] = [];
+  // Patterns without "/" match at any depth, as in .gitignore
+  const globRegex = glob ? globToRegExp(glob, { braces: true, anyDepth: !glob.includes("/") }) : null;
+  let truncated = false;
+
+  function walk(absDir: string, relDir: string, parentIgnore: IgnoreMatcher): void {
+    const ignore = parentIgnore.forDirectory(absDir, relDir);
+    let entries: fs.Dirent[];
+    try {
+      entries = fs.readdirSync(absDir, { withFileTypes: true });
+    } catch {
+      return;
+    }
+    entries.sort((a, b) => a.name.localeCompare(b.name));
+
+    for (const entry of entries) {
+      if (files.length >= MAX_FILES) {
+        truncated = true;
+        return;
+      }
+
+      const relPath = relDir ? `${relDir}/${entry.name}` : entry.name;
+      if (entry.isDirectory()) {
+        if (isExcludedDir(entry.name, relPath, ignore)) continue;
+        walk(path.join(absDir, entry.name), relPath, ignore);
+      } else if (entry.isFile()) {
+        if (isExcludedFile(entry.name, relPath, ignore)) continue;
+        // Never surface keys, .env files and other credentials
+        if (isBlockedPath(relPath)) continue;
+        if (globRegex && !globRegex.test(relPath)) continue;
+        files.push(relPath);
+      }
+    }
+  }
+
+  walk(rootDir, "", IgnoreMatcher.forRoot(rootDir));
+  return { files, truncated };
+}
+
+/**
+ * Read a text file, skipping large and binary files
+ */
+function readTextFile(absPath: string): string | null {
+  try {
+    if (fs.statSync(absPath).size > MAX_FILE_SIZE) return null;
+    return decodeText(fs.readFileSync(absPath))?.text ?? null;
+  } catch {
+    return null;
+  }
+}
+
+function clip(line: string): string {
+  return line.length > MAX_LINE_LENGTH ? `${line.slice(0, MAX_LINE_LENGTH)}...` : line;
+}
+
+/**
+ * Whether a regex source nests quantifiers, e.g. (a+)+
+ */
+export function hasNestedQuantifier(source: string): boolean {
+  return NESTED_QUANTIFIER.test(source);
+}
+
+/**
+ * Search files under rootDir (or rootDir itself when it is a file)
+ *
+ * Only the first maxPerFile matches of each file are kept with their
+ * context lines; later matches are counted. The search stops after
+ * SEARCH_TIME_LIMIT_MS.
+ */
+export function grepFiles(rootDir: string, regex: RegExp, options: SearchOptions): GrepSummary {
+  const isFile = fs.statSync(rootDir).isFile();
+  const { files, truncated } = isFile
+    ? { files: [path.basename(rootDir)], truncated: false }
+    : collectFiles(rootDir, options.glob);
+  const baseDir = isFile ? path.dirname(rootDir) : rootDir;
+  const deadline = Date.now() + SEARCH_TIME_LIMIT_MS;
+
+  const results: FileMatches[] = [];
+  let totalMatches = 0;
+  let filesSearched = 0;
+  let timedOut = false;
+
+  for (const relPath of files) {
+    if (Date.now() > deadline) {
+      timedOut = true;
+      break;
+    }
+    filesSearched++;
+
+    const content = readTextFile(path.join(baseDir, relPath));
+    if (content === null) continue;
+
+    const fileLines = content.split("\n");
+    const matchLines: number[] = [];
+    const lines = new Map<number, string>();
+    let matchCount = 0;
+    for (let i = 0; i < fileLines.length; i++) {
+      if (!regex.test(fileLines[i]!)) continue;
+      matchCount++;
+      if (matchLines.length < options.maxPerFile) {
+        matchLines.push(i);
+        const end = Math.min(fileLines.length - 1, i + options.context);
+        for (let j = Math.max(0, i - options.context); j <= end; j++) {
+          if (!lines.has(j)) lines.set(j, clip(fileLines[j]!));
+        }
+      }
+      if (Date.now() > deadline) {
+        timedOut = true;
+        break;
+      }
+    }
+
+    if (matchCount > 0) {
+      results.push({ relPath, matchCount, matchLines, lines });
+      totalMatches += matchCount;
+    }
+    if (timedOut) break;
+  }
+
+  return { files: results, totalMatches, filesSearched, truncated, timedOut };
+}
+
+interface MatchGroup {
+  text: string;
+  matches: number;
+}
+
+/**
+ * Group shown matches of one file with their context lines
+ * Match lines use "N:" and context lines "N-", as in grep.
+ */
+function groupMatches(file: FileMatches, shown: number[], context: number): MatchGroup[] {
+  const groups: MatchGroup[] = [];
+  let current: string[] = [];
+  let matches = 0;
+  let lastLine = -1;
+
+  for (const match of shown) {
+    const start = Math.max(0, match - context, lastLine + 1);
+    let end = match;
+    while (end < match + context && file.lines.has(end + 1)) end++;
+
+    if (current.length > 0 && start > lastLine + 1) {
+      groups.push({ text: current.join("\n"), matches });
+      current = [];
+      matches = 0;
+    }
+    for (let i = start; i <= end; i++) {
+      current.push(`${i + 1}${shown.includes(i) ? ":" : "-"}${file.lines.get(i)}`);
+    }
+    matches++;
+    lastLine = Math.max(lastLine, end);
+  }
+  if (current.length > 0) groups.push({ text: current.join("\n"), matches });
+
+  return groups;
+}
+
+function formatSearched(summary: GrepSummary): string {
+  const more = summary.truncated || summary.timedOut ? "+" : "";
+  const searched = `${summary.filesSearched}${more} files searched`;
+  return summary.timedOut ? `${searched}, time limit reached` : searched;
+}
+
+function formatHeader(pattern: string, summary: GrepSummary, shownMatches: number): string {
+  return (
+    `[grep] /${pattern}/ ${summary.totalMatches} matches in ${summary.files.length} files` +
+    (shownMatches < summary.totalMatches ? `, showing ${shownMatches}` : "") +
+    ` (${formatSearched(summary)})`
+  );
+}
+
+function notShownEntry(file: FileMatches): string {
+  return `${file.relPath} (${file.matchCount})`;
+}
+
+function formatNotShown(remaining: FileMatches[]): string {
+  const names = remaining.slice(0, MAX_NOT_SHOWN).map(notShownEntry);
+  const more = remaining.length > names.length ? `, +${remaining.length - names.length} more` : "";
+  return `Not shown: ${names.join(", ")}${more}`;
+}
+
+/**
+ * Upper bound on the tokens of the header and "Not shown:" lines
+ */
+function reservedTokens(pattern: string, summary: GrepSummary): number {
+  const header = countTokens(formatHeader(pattern, summary, 0)) + 1;
+  if (summary.files.length <= 1) return header;
+
+  // Any MAX_NOT_SHOWN files may end up listed; reserve for the longest
+  const entryCosts = summary.files
+    .map((file) => countTokens(notShownEntry(file)) + 1)
+    .sort((a, b) => b - a)
+    .slice(0, MAX_NOT_SHOWN);
+  const footer =
+    countTokens(`Not shown: , +${summary.files.length} more`) +
+    entryCosts.reduce((sum, cost) => sum + cost, 0) +
+    2;
+  return header + footer;
+}
+
+/**
+ * Format search results within the token budget
+ */
+export function formatGrepResults(
+  summary: GrepSummary,
+  pattern: string,
+  options: GrepOptions
+): string {
+  const { files } = summary;
+
+  if (files.length === 0) {
+    return `[grep] /${pattern}/ no matches (${formatSearched(summary)})`;
+  }
+
+  const sections: string[] = [];
+  const budget = options.maxTokens - reservedTokens(pattern, summary);
+  let used = 0;
+  let shownMatches = 0;
+  let shownFiles = 0;
+
+  for (const file of files) {
+    const shown = file.matchLines.slice(0, options.maxPerFile);
+    const title =
+      file.matchCount > shown.length
+        ? `${file.relPath} (${file.matchCount} matches, showing ${shown.length})`
+        : file.relPath;
+
+    // Add whole context groups until the budget runs out
+    const groups = groupMatches(file, shown, options.context);
+    const kept: string[] = [];
+    let cost = countTokens(title) + 1;
+    for (const group of groups) {
+      const groupCost = countTokens(group.text) + 2;
+      if (used + cost + groupCost > budget) break;
+      kept.push(group.text);
+      cost += groupCost;
+      shownMatches += group.matches;
+    }
+    if (kept.length === 0) break;
+
+    sections.push(`${title}\n${kept.join("\n--\n")}`);
+    used += cost;
+    shownFiles++;
+    if (kept.length < groups.length) break;
+  }
+
+  const parts = [formatHeader(pattern, summary, shownMatches), "", sections.join("\n\n")];
+
+  const remaining = files.slice(shownFiles);
+  if (remaining.length > 0) parts.push("", formatNotShown(remaining));
+
+  return parts.join("\n");
+}
+
+export async function executeGrepContext(
+  args: unknown
+): Promise<{ content: Array<{ type: "text"; text: string }>; isError?: boolean }> {
+  const parseResult = inputSchema.safeParse(args);
+  if (!parseResult.success) {
+    return {
+      content: [
+        {
+          type: "text",
+          text: `Invalid input: ${parseResult.error.errors.map((e) => e.message).join(", ")}`,
+        },
+      ],
+      isError: true,
+    };
+  }
+
+  const input = parseResult.data;
+  const workingDir = process.cwd();
+
+  const validation = validatePath(input.path, workingDir);
+  if (!validation.safe || !validation.resolvedPath) {
+    return { content: [{ type: "text", text: validation.error || "Invalid path" }], isError: true };
+  }
+  if (!fs.existsSync(validation.resolvedPath)) {
+    return { content: [{ type: "text", text: `Path not found: ${input.path}` }], isError: true };
+  }
+
+  if (!input.literal && hasNestedQuantifier(input.pattern)) {
+    return {
+      content: [
+        {
+          type: "text",
+          text: "Pattern rejected: nested quantifiers such as (a+)+ can take exponential time",
+        },
+      ],
+      isError: true,
+    };
+  }
+
+  let regex: RegExp;
+  try {
+    const source = input.literal
+      ? input.pattern.replace(/[.*+?^${}()|[\]\\]/g, "\\$&")
+      : input.pattern;
+    regex = new RegExp(source, input.ignoreCase ? "i" : "");
+  } catch {
+    return {
+      content: [{ type: "text", text: `Invalid regex pattern: ${input.pattern}` }],
+      isError: true,
+    };
+  }
+
+  if (input.glob) {
+    try {
+      globToRegExp(input.glob, { braces: true });
+    } catch {
+      return { content: [{ type: "text", text: `Invalid glob: ${input.glob}` }], isError: true };
+    }
+  }
+
+  const summary = grepFiles(validation.resolvedPath, regex, input);
+  const text = formatGrepResults(summary, input.pattern, input);
+
+  return { content: [{ type: "text", text }] };
+}
+
+export const grepContextTool: ToolDefinition = {
+  name: "grep_context",
+  description:
+    "Regex search with context lines per match (default 2), capped per file (default 5) and " +
+    "truncated to maxTokens (default 2000). Skips dependencies, binaries, sensitive files and " +
+    ".gitignore/.ctxoptignore paths. Use instead of grep in a shell.",
+  inputSchema: grepContextSchema,
+  annotations: {
+    title: "Grep Context",
+    readOnlyHint: true,
+    idempotentHint: true,
+  },
+  execute: executeGrepContext,
+};
@@ -0,0 +1,383 @@
+/**
+ * Project Map Tool
+ *
+ * Token-budgeted overview of a repository: a directory tree annotated
+ * with token estimates plus one-line summaries of key files, so the
+ * model can orient itself without reading dozens of files.
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { z } from "zod";
+
+import type { ToolDefinition } from "./registry.js";
+import { parseFile } from "../ast/index.js";
+import { isBlockedPath, validatePath } from "../sandbox/security/path-validator.js";
+import { isExcludedDir, isExcludedFile } from "../utils/file-filters.js";
+import { IgnoreMatcher } from "../utils/ignore-rules.js";
+import { detectLanguageFromPath } from "../utils/language-detector.js";
+import { detectProject, getProjectSummary } from "../utils/project-detector.js";
+import { decodeText } from "../utils/text-encoding.js";
+import { countTokens } from "../utils/token-counter.js";
+
+const MAX_FILES = 5000;
+const MAX_FILES_PER_DIR = 15;
+const MAX_KEY_FILES = 15;
+const MAX_SUMMARY_FILE_SIZE = 512 * 1024;
+
+const MANIFEST_FILES = new Set([
+  "package.json",
+  "Cargo.toml",
+  "pyproject.toml",
+  "go.mod",
+  "composer.json",
+  "Package.swift",
+]);
+
+const ENTRY_POINT_PATTERN = /^(index|main|server|app|cli|lib|mod)\.[a-z]+$/;
+
+export interface FileEntry {
+  name: string;
+  relPath: string;
+  absPath: string;
+  depth: number;
+  size: number;
+  /** Estimated tokens (~4 bytes per token) */
+  tokens: number;
+}
+
+export interface DirNode {
+  name: string;
+  depth: number;
+  files: FileEntry[];
+  dirs: DirNode[];
+  fileCount: number;
+  tokens: number;
+}
+
+// Minimal schema
+export const projectMapSchema = {
+  type: "object" as const,
+  properties: {
+    path: { type: "string" },
+    maxTokens: { type: "number" },
+    depth: { type: "number" },
+    summaries: { type: "boolean" },
+  },
+};
+
+const inputSchema = z.object({
+  path: z.string().optional().default("."),
+  maxTokens: z.number().int().min(200).max(50000).optional().default(2000),
+  depth: z.number().int().min(1).max(10).optional().default(4),
+  summaries: z.boolean().optional().default(true),
+});
+
+function formatTokens(tokens: number): string {
+  return tokens < 1000 ? `${tokens}` : `${(tokens / 1000).toFixed(1)}k`;
+}
+
+/**
+ * Walk the directory and build a tree with token estimates
+ */
+export function buildTree(rootDir: string): { root: DirNode; truncated: boolean } {
+  let seenFiles = 0;
+  let truncated = false;
+
+  function visit(
+    absDir: string,
+    relDir: string,
+    name: string,
+    depth: number,
+    parentIgnore: IgnoreMatcher
+  ): DirNode {
+    const node: DirNode = { name, depth, files: [], dirs: [], fileCount: 0, tokens: 0 };
+    const ignore = parentIgnore.forDirectory(absDir, relDir);
+
+    let entries: fs.Dirent[];
+    try {
+      entries = fs.readdirSync(absDir, { withFileTypes: true });
+    } catch {
+      return node;
+    }
+    entries.sort((a, b) => a.name.localeCompare(b.name));
+
+    for (const entry of entries) {
+      // Symlinks are not followed (Dirent reflects lstat)
+      const absPath = path.join(absDir, entry.name);
+      const relPath = relDir ? `${relDir}/${entry.name}` : entry.name;
+
+      if (entry.isDirectory()) {
+        if (isExcludedDir(entry.name, relPath, ignore)) continue;
+        const child = visit(absPath, relPath, entry.name, depth + 1, ignore);
+        if (child.fileCount > 0) {
+          node.dirs.push(child);
+          node.fileCount += child.fileCount;
+          node.tokens += child.tokens;
+        }
+      } else if (entry.isFile()) {
+        if (isExcludedFile(entry.name, relPath, ignore)) continue;
+        // Sensitive files are neither listed nor summarized
+        if (isBlockedPath(relPath)) continue;
+        if (seenFiles >= MAX_FILES) {
+          truncated = true;
+          continue;
+        }
+
+        let size: number;
+        try {
+          size = fs.statSync(absPath).size;
+        } catch {
+          continue;
+        }
+
+        seenFiles++;
+        const tokens = Math.ceil(size / 4);
+        node.files.push({ name: entry.name, relPath, absPath, depth: depth + 1, size, tokens });
+        node.fileCount++;
+        node.tokens += tokens;
+      }
+    }
+
+    return node;
+  }
+
+  const root = visit(rootDir, "", ".", 0, IgnoreMatcher.forRoot(rootDir));
+  return { root, truncated };
+}
+
+/**
+ * Render the tree down to a depth limit
+ */
+function renderTree(root: DirNode, depthLimit: number): string[] {
+  const lines: string[] = [];
+
+  const visit = (node: DirNode, indent: string) => {
+    if (node.depth >= depthLimit) return;
+
+    for (const dir of node.dirs) {
+      lines.push(`${indent}${dir.name}/ (${dir.fileCount} files, ~${formatTokens(dir.tokens)})`);
+      visit(dir, `${indent}  `);
+    }
+
+    const shown = node.files.slice(0, MAX_FILES_PER_DIR);
+    for (const file of shown) {
+      lines.push(`${indent}${file.name} (~${formatTokens(file.tokens)})`);
+    }
+    const hidden = node.files.slice(MAX_FILES_PER_DIR);
+    if (hidden.length > 0) {
+      const hiddenTokens = hidden.reduce((sum, f) => sum + f.tokens, 0);
+      lines.push(`${indent}... +${hidden.length} files (~${formatTokens(hiddenTokens)})`);
+    }
+  };
+
+  visit(root, "");
+  return lines;
+}
+
+/**
+ * Pick the deepest tree rendering that fits the budget
+ */
+export function fitTree(root: DirNode, maxDepth: number, budget: number): { text: string; depth: number } {
+  let best: { text: string; depth: number } | null = null;
+
+  for (let depth = 1; depth <= maxDepth; depth++) {
+    const text = renderTree(root, depth).join("\n");
+    if (countTokens(text) > budget) break;
+    best = { text, depth };
+  }
+
+  if (best) return best;
+
+  // Even the top level is too large: keep as many lines as fit, leaving room for the marker
+  const lines = renderTree(root, 1);
+  const markerCost = countTokens(`\n... (${lines.length} more entries)`);
+  const kept: string[] = [];
+  for (const line of lines) {
+    if (countTokens([...kept, line].join("\n")) + markerCost > budget) break;
+    kept.push(line);
+  }
+  kept.push(`... (${lines.length - kept.length} more entries)`);
+  return { text: kept.join("\n"), depth: 1 };
+}
+
+function collectFiles(node: DirNode, out: FileEntry[] = []): FileEntry[] {
+  out.push(...node.files);
+  for (const dir of node.dirs) collectFiles(dir, out);
+  return out;
+}
+
+/**
+ * Rank files worth summarizing: manifests, READMEs, entry points, then the largest sources
+ */
+export function pickKeyFiles(root: DirNode): FileEntry[] {
+  const score = (file: FileEntry): number => {
+    if (file.depth === 1 && MANIFEST_FILES.has(file.name)) return 1000;
+    if (file.depth === 1 && /^readme(\.|$)/i.test(file.name)) return 900;
+    if (ENTRY_POINT_PATTERN.test(file.name) && detectLanguageFromPath(file.name) !== "unknown") {
+      return 500 - file.depth * 50;
+    }
+    if (detectLanguageFromPath(file.name) !== "unknown") return Math.min(100, file.tokens / 100);
+    return 0;
+  };
+
+  return collectFiles(root)
+    .map((file) => ({ file, score: score(file) }))
+    .filter((entry) => entry.score > 0)
+    .sort((a, b) => b.score - a.score)
+    .slice(0, MAX_KEY_FILES * 2)
+    .map((entry) => entry.file);
+}
+
+function truncate(text: string, maxLen: number): string {
+  return text.length <= maxLen ? text : `${text.slice(0, maxLen - 3)}...`;
+}
+
+/**
+ * One-line summary of a key file, or null if nothing useful can be said
+ */
+function summarizeFile(file: FileEntry): string | null {
+  if (file.size > MAX_SUMMARY_FILE_SIZE) return null;
+
+  let content: string;
+  try {
+    const decoded = decodeText(fs.readFileSync(file.absPath));
+    if (!decoded) return null;
+    content = decoded.text;
+  } catch {
+    return null;
+  }
+
+  if (file.name === "package.json") {
+    try {
+      const pkg = JSON.parse(content) as {
+        name?: string;
+        version?: string;
+        description?: string;
+        scripts?: Record<string, string>;
+        dependencies?: Record<string, string>;
+        devDependencies?: Record<string, string>;
+      };
+      const parts = [`${pkg.name ?? "unnamed"}${pkg.version ? `@${pkg.version}` : ""}`];
+      if (pkg.description) parts.push(truncate(pkg.description, 80));
+      const scripts = Object.keys(pkg.scripts ?? {});
+      if (scripts.length > 0) parts.push(`scripts: ${scripts.slice(0, 6).join(", ")}`);
+      const deps =
+        Object.keys(pkg.dependencies ?? {}).length + Object.keys(pkg.devDependencies ?? {}).length;
+      if (deps > 0) parts.push(`${deps} deps`);
+      return parts.join(" | ");
+    } catch {
+      return null;
+    }
+  }
+
+  const lines = content.split("\n").map((l) => l.trim());
+
+  if (/^readme(\.|$)/i.test(file.name)) {
+    const title = lines.find((l) => l.startsWith("#"))?.replace(/^#+\s*/, "");
+    const intro = lines.find((l) => l && !l.startsWith("#") && !l.startsWith("<") && !l.startsWith("["));
+    const summary = [title, intro]
+      .filter((t): t is string => !!t)
+      .map((t) => truncate(t, 100))
+      .join(": ");
+    return summary || null;
+  }
+
+  if (MANIFEST_FILES.has(file.name)) {
+    const meaningful = lines.filter((l) => l && !l.startsWith("#") && !l.startsWith("//"));
+    return meaningful.length > 0 ? truncate(meaningful.slice(0, 3).join(" | "), 120) : null;
+  }
+
+  const language = detectLanguageFromPath(file.name);
+  if (language === "unknown") return null;
+
+  const structure = parseFile(content, language, "quick");
+  const elements = [
+    ...structure.classes,
+    ...structure.interfaces,
+    ...structure.types,
+    ...structure.functions.filter((f) => !f.parent),
+  ];
+  const exported = elements.filter((e) => e.isExported);
+  const names = (exported.length > 0 ? exported : elements).map((e) => e.name);
+  if (names.length === 0) return null;
+
+  const more = names.length > 8 ? ` +${names.length - 8}` : "";
+  return `${exported.length > 0 ? "exports" : "defines"} ${names.slice(0, 8).join(", ")}${more}`;
+}
+
+export async function executeProjectMap(
+  args: unknown
+): Promise<{ content: Array<{ type: "text"; text: string }>; isError?: boolean }> {
+  const input = inputSchema.parse(args);
+  const workingDir = process.cwd();
+
+  const validation = validatePath(input.path, workingDir);
+  if (!validation.safe || !validation.resolvedPath) {
+    return { content: [{ type: "text", text: validation.error || "Invalid path" }], isError: true };
+  }
+  const rootDir = validation.resolvedPath;
+
+  try {
+    if (!fs.statSync(rootDir).isDirectory()) {
+      return { content: [{ type: "text", text: `Not a directory: ${input.path}` }], isError: true };
+    }
+  } catch {
+    return { content: [{ type: "text", text: `Directory not found: ${input.path}` }], isError: true };
+  }
+
+  const { root, truncated } = buildTree(rootDir);
+  const project = detectProject(rootDir);
+
+  const header = [
+    `[project_map] ${project ? getProjectSummary(project).replace(/\*\*/g, "") : path.basename(rootDir)}`,
+    `${root.fileCount}${truncated ? "+" : ""} files, ~${formatTokens(root.tokens)} tokens`,
+  ].join("\n");
+
+  const available = input.maxTokens - countTokens(header);
+  const treeBudget = input.summaries ? Math.floor(available * 0.6) : available;
+  const tree = fitTree(root, input.depth, treeBudget);
+
+  const parts = [header, "", tree.text];
+  let used = countTokens(parts.join("\n"));
+
+  if (input.summaries) {
+    const summaryLines: string[] = [];
+    for (const file of pickKeyFiles(root)) {
+      if (summaryLines.length >= MAX_KEY_FILES) break;
+      const summary = summarizeFile(file);
+      if (!summary) continue;
+
+      const line = `${file.relPath}: ${summary}`;
+      const cost = countTokens(line) + 1;
+      if (used + cost > input.maxTokens) break;
+      summaryLines.push(line);
+      used += cost;
+    }
+
+    if (summaryLines.length > 0) {
+      parts.push("", "KEY FILES:", ...summaryLines);
+    }
+  }
+
+  if (tree.depth < input.depth) {
+    parts.push("", `(tree limited to depth ${tree.depth} by budget; map a subdirectory for more detail)`);
+  }
+
+  return { content: [{ type: "text", text: parts.join("\n") }] };
+}
+
+export const projectMapTool: ToolDefinition = {
+  name: "project_map",
+  description:
+    "Repository overview within maxTokens (default 2000): directory tree (depth default 4) with " +
+    "token estimates and one-line summaries of key files (manifests, README, entry points). " +
+    "Use to orient before reading files. Honors .gitignore and .ctxoptignore.",
+  inputSchema: projectMapSchema,
+  annotations: {
+    title: "Project Map",
+    readOnlyHint: true,
+    idempotentHint: true,
+  },
+  execute: executeProjectMap,
+};
@@ -0,0 +1,139 @@
+/**
+ * Smart File Diff Tool
+ *
+ * Returns only what changed in a file since it was last read,
+ * instead of re-reading the whole file after an edit.
+ *
+ * Snapshots are recorded by smart_file_read and by this tool.
+ */
+
+import { z } from "zod";
+
+import type { ToolDefinition } from "./registry.js";
+import { getSnapshotStore } from "../cache/file-snapshots.js";
+import { validatePath } from "../sandbox/security/path-validator.js";
+import { diffLines, formatUnifiedDiff } from "../utils/line-diff.js";
+import { readFileSafe } from "../utils/safe-file-reader.js";
+import { countTokens } from "../utils/token-counter.js";
+
+// Minimal schema
+export const smartFileDiffSchema = {
+  type: "object" as const,
+  properties: {
+    filePath: { type: "string" },
+    context: { type: "number" },
+    update: {
+      type: "boolean",
+      description: "Diff against this version next time (default: true)",
+    },
+  },
+  required: ["filePath"],
+};
+
+const inputSchema = z.object({
+  filePath: z.string(),
+  context: z.number().int().min(0).max(20).optional().default(3),
+  update: z.boolean().optional().default(true),
+});
+
+export async function executeSmartFileDiff(
+  args: unknown
+): Promise<{ content: Array<{ type: "text"; text: string }>; isError?: boolean }> {
+  const input = inputSchema.parse(args);
+  const workingDir = process.cwd();
+
+  const validation = validatePath(input.filePath, workingDir);
+  if (!validation.safe || !validation.resolvedPath) {
+    return {
+      content: [{ type: "text", text: validation.error || "Invalid path" }],
+      isError: true,
+    };
+  }
+  const resolvedPath = validation.resolvedPath;
+
+  // Read the same way as smart_file_read so snapshots compare equal
+  const read = await readFileSafe(resolvedPath);
+  if (read.kind !== "text") {
+    let reason = "file not found";
+    if (read.kind === "unsupported") reason = `it ${read.reason}`;
+    else if (read.kind === "binary") reason = "binary file";
+    else if (read.kind === "too-large") reason = "file too large to diff";
+    return {
+      content: [{ type: "text", text: `Cannot diff ${input.filePath}: ${reason}` }],
+      isError: true,
+    };
+  }
+  const current = read.text;
+
+  const store = getSnapshotStore();
+  const previous = store.get(resolvedPath);
+  const totalLines = current.split("\n").length;
+
+  if (!previous) {
+    store.record(resolvedPath, current);
+    return {
+      content: [
+        {
+          type: "text",
+          text:
+            `[diff] ${input.filePath}: no previous read, baseline recorded ` +
+            `(${totalLines} lines, ${countTokens(current)} tokens). ` +
+            "Call again after the file changes to get only the diff.",
+        },
+      ],
+    };
+  }
+
+  if (previous.content === current) {
+    return {
+      content: [
+        { type: "text", text: `[diff] ${input.filePath}: unchanged since last read (${totalLines} lines)` },
+      ],
+    };
+  }
+
+  const fullTokens = countTokens(current);
+  const result = diffLines(previous.content, current, { context: input.context });
+
+  if (input.update) {
+    store.record(resolvedPath, current);
+  }
+
+  if (!result) {
+    return {
+      content: [
+        {
+          type: "text",
+          text:
+            `[diff] ${input.filePath}: changed too much for a useful diff ` +
+            `(${totalLines} lines, ${fullTokens} tokens). Re-read it with smart_file_read.`,
+        },
+      ],
+    };
+  }
+
+  const diff = formatUnifiedDiff(result, `a/${input.filePath}`, `b/${input.filePath}`);
+  const diffTokens = countTokens(diff);
+  const savingsPercent = fullTokens > 0 ? Math.round((1 - diffTokens / fullTokens) * 100) : 0;
+
+  const header =
+    `[diff] ${input.filePath}: +${result.additions}/-${result.deletions} in ${result.hunks.length} ` +
+    `hunk${result.hunks.length === 1 ? "" : "s"}, ${fullTokens}→${diffTokens} tokens` +
+    (savingsPercent > 0 ? ` (-${savingsPercent}%)` : "");
+
+  return { content: [{ type: "text", text: `${header}\n${diff}` }] };
+}
+
+export const smartFileDiffTool: ToolDefinition = {
+  name: "smart_file_diff",
+  description:
+    "Show only what changed in a file since it was last read (via smart_file_read or this tool). " +
+    "Use after edits instead of re-reading the whole file.",
+  inputSchema: smartFileDiffSchema,
+  annotations: {
+    title: "Smart File Diff",
+    readOnlyHint: false, // Records the new snapshot unless update=false
+    idempotentHint: false,
+  },
+  execute: executeSmartFileDiff,
+};
@@ -7,7 +7,6 @@
  * Security: Path sandboxing restricts file access to the working directory.
  */
 
-import * as fs from "fs/promises";
 import * as path from "path";
 import { z } from "zod";
 
@@ -21,8 +20,11 @@ import {
   type ExtractedContent,
 } from "../ast/index.js";
 import { detectLanguageFromPath } from "../utils/language-detector.js";
+import { isWithinDirectory, normalizeInputPath } from "../utils/path-normalizer.js";
+import { readFileSafe, DEFAULT_MAX_FILE_BYTES, type SafeReadResult } from "../utils/safe-file-reader.js";
 import type { ToolDefinition } from "./registry.js";
 import { getGlobalCache } from "../cache/smart-cache.js";
+import { getSnapshotStore } from "../cache/file-snapshots.js";
 import type { FileStructure, SupportedLanguage } from "../ast/types.js";
 
 // Parseable languages (excluding json, yaml, unknown)
@@ -76,18 +78,14 @@ function validatePath(
   filePath: string,
   workingDir: string
 ): { safe: boolean; error?: string; resolvedPath?: string } {
-  // Resolve to absolute path
-  const resolvedPath = path.isAbsolute(filePath)
-    ? path.normalize(filePath)
-    : path.resolve(workingDir, filePath);
-
-  // Normalize both paths for comparison
-  const normalizedResolved = path.normalize(resolvedPath);
-  const normalizedWorkingDir = path.normalize(workingDir);
-
-  // Check if resolved path is within working directory
-  if (!normalizedResolved.startsWith(normalizedWorkingDir + path.sep) &&
-      normalizedResolved !== normalizedWorkingDir) {
+  // Resolve to absolute path (Windows long-path and MSYS forms included)
+  const normalizedInput = normalizeInputPath(filePath);
+  const resolvedPath = path.isAbsolute(normalizedInput)
+    ? path.normalize(normalizedInput)
+    : path.resolve(workingDir, normalizedInput);
+
+  // Check if resolved path is within working directory (case-insensitive on Windows)
+  if (!isWithinDirectory(resolvedPath, workingDir)) {
     return {
       safe: false,
       error: `Access denied: Path '${filePath}' is outside the working directory. Only files within '${workingDir}' can be read.`,
@@ -450,6 +448,30 @@ function formatSkeletonOutput(
   return parts.join("\n");
 }
 
+function formatBytes(bytes: number): string {
+  if (bytes < 1024) return `${bytes} B`;
+  if (bytes < 1024 * 1024) return `${(bytes / 1024).toFixed(1)} KB`;
+  return `${(bytes / (1024 * 1024)).toFixed(1)} MB`;
+}
+
+/**
+ * Head/tail preview for files above the size cap
+ */
+function formatTooLarge(
+  filePath: string,
+  read: Extract<SafeReadResult, { kind: "too-large" }>
+): string {
+  const sparse = read.sparse ? ", sparse" : "";
+  return [
+    `${filePath}: too large to read fully (${formatBytes(read.size)}${sparse}, limit ${formatBytes(DEFAULT_MAX_FILE_BYTES)}). Showing head and tail only.`,
+    "",
+    "--- head ---",
+    read.head,
+    "--- tail ---",
+    read.tail,
+  ].join("\n");
+}
+
 export async function executeSmartFileRead(
   args: unknown
 ): Promise<{ content: Array<{ type: "text"; text: string }> }> {
@@ -466,19 +488,34 @@ export async function executeSmartFileRead(
 
   const resolvedPath = validation.resolvedPath;
 
-  // Check if file exists
-  try {
-    await fs.access(resolvedPath);
-  } catch {
+  // Read file content (size/type guarded, BOM/UTF-16 aware, CRLF normalized)
+  const read = await readFileSafe(resolvedPath);
+  if (read.kind === "not-found") {
     return {
       content: [{ type: "text", text: `File not found: ${resolvedPath}` }],
     };
   }
-
-  // Read file content
-  const content = await fs.readFile(resolvedPath, "utf-8");
+  if (read.kind === "unsupported") {
+    return {
+      content: [{ type: "text", text: `Cannot read ${input.filePath}: it ${read.reason}` }],
+    };
+  }
+  if (read.kind === "binary") {
+    return {
+      content: [
+        { type: "text", text: `Cannot read binary file: ${input.filePath} (${formatBytes(read.size)})` },
+      ],
+    };
+  }
+  if (read.kind === "too-large") {
+    return { content: [{ type: "text", text: formatTooLarge(input.filePath, read) }] };
+  }
+  const content = read.text;
   const totalLines = content.split("\n").length;
 
+  // Remember what was read so smart_file_diff can return only the changes later
+  getSnapshotStore().record(resolvedPath, content);
+
   // Detect or force language
   let language: SupportedLanguage;
   if (input.language) {
@@ -16,8 +16,10 @@ import {
   type LogSummary,
   type SummarizeOptions,
   MAX_ENTRIES,
+  summarizeWithinBudget,
 } from "../summarizers/index.js";
 import { detectLogType } from "../utils/log-parser.js";
+import { countTokens } from "../utils/token-counter.js";
 
 // Input schema with semantic descriptions
 export const summarizeLogsSchema = {
@@ -41,6 +43,10 @@ export const summarizeLogsSchema = {
       description: "Level of detail in output",
       default: "normal",
     },
+    maxTokens: {
+      type: "number",
+      description: "Max output tokens",
+    },
   },
   required: ["logs"],
 };
@@ -70,6 +76,7 @@ const inputSchema = z.object({
     .array(z.enum(["errors", "warnings", "performance", "timeline"]))
     .optional(),
   detail: z.enum(["minimal", "normal", "detailed"]).optional().default("normal"),
+  maxTokens: z.number().int().min(50).max(50000).optional(),
   timeframe: z
     .object({
       start: z.string().optional(),
@@ -101,7 +108,10 @@ export async function executeSummarizeLogs(
   const summary = summarizer.summarize(input.logs, options);
 
   // Format output
-  const output = formatSummary(summary, options);
+  let output = formatSummary(summary, options);
+  if (input.maxTokens && countTokens(output) > input.maxTokens) {
+    output = await formatWithinBudget(input.logs, summary, input.maxTokens);
+  }
 
   // Calculate token savings
   const originalTokens = Math.ceil(input.logs.length / 4);
@@ -242,6 +252,25 @@ function formatSummary(summary: LogSummary, options: SummarizeOptions): string {
   return parts.join("\n");
 }
 
+/**
+ * Format a log excerpt that fits the token budget
+ */
+async function formatWithinBudget(
+  logs: string,
+  summary: LogSummary,
+  maxTokens: number
+): Promise<string> {
+  const overview = `**${summary.overview}**\n`;
+  const header = `## Log Excerpt (${summary.logType})\n\n${overview}`;
+  const budget = Math.max(maxTokens - countTokens(header) - 1, 0);
+  const excerpt = await summarizeWithinBudget(logs, budget);
+
+  if (excerpt.method === "external") {
+    return `## Log Summary (${summary.logType})\n\n${overview}\n${excerpt.text}`;
+  }
+  return `${header}\n${excerpt.text}`;
+}
+
 /**
  * Truncate a string to a maximum length
  */
@@ -26,6 +26,9 @@ import { conversationCompressTool } from "./conversation-compress.js";
 import { deduplicateErrorsTool } from "./deduplicate-errors.js";
 import { smartCacheTool } from "./smart-cache-tool.js";
 import { smartPipelineTool } from "./smart-pipeline.js";
+import { smartFileDiffTool } from "./smart-file-diff.js";
+import { projectMapTool } from "./project-map.js";
+import { grepContextTool } from "./grep-context.js";
 import type { ToolDefinition } from "./registry.js";
 
 // ============================================================================
@@ -58,12 +61,15 @@ const TOKEN_BUDGETS = {
   context_budget: 105,
 
   // Logs category
-  summarize_logs: 180, // +65 for outputSchema + semantic descriptions
+  summarize_logs: 185, // +65 for outputSchema + semantic descriptions, +5 for maxTokens (2026-10)
   deduplicate_errors: 65,
 
   // Code category
   code_skeleton: 75,
   smart_cache: 90,
+  smart_file_diff: 95,
+  project_map: 110,
+  grep_context: 165,
 
   // Pipeline category
   smart_pipeline: 80,
@@ -81,8 +87,9 @@ const CORE_TOOLS_BUDGET = 700;
  * Maximum tokens for all tools combined.
  * 2024-12: Reduced from 1500 after aggressive schema optimization
  * 2026-01: Increased to 1800 for MCP 2025-06-18 compliance
+ * 2026-10: Increased to 1950 for smart_file_diff, project_map and grep_context
  */
-const ALL_TOOLS_BUDGET = 1800;
+const ALL_TOOLS_BUDGET = 1950;
 
 // ============================================================================
 // Helper Functions
@@ -126,6 +133,9 @@ const ALL_TOOLS: ToolDefinition[] = [
   deduplicateErrorsTool,
   smartCacheTool,
   smartPipelineTool,
+  smartFileDiffTool,
+  projectMapTool,
+  grepContextTool,
 ];
 
 const CORE_TOOLS: ToolDefinition[] = [
@@ -325,9 +335,10 @@ describe("Regression Prevention", () => {
    * - Added semantic property descriptions for LLM guidance
    * - Added outputSchema for structured validation
    * - Added annotations (readOnlyHint, idempotentHint, etc.)
+   * 2026-10: auto_optimize gained the maxTokens budget option
    */
   const CURRENT_SNAPSHOT = {
-    auto_optimize: 184, // +104 for outputSchema + semantic descriptions
+    auto_optimize: 197, // +104 for outputSchema + semantic descriptions, +13 for maxTokens
     smart_file_read: 263, // +157 for outputSchema + property descriptions
     discover_tools: 167, // +89 for outputSchema + semantic hints
   };
@@ -0,0 +1,136 @@
+/**
+ * Streamable HTTP Transport Tests
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from "vitest";
+import type * as http from "http";
+import type { AddressInfo } from "net";
+import { Server } from "@modelcontextprotocol/sdk/server/index.js";
+import { ListToolsRequestSchema } from "@modelcontextprotocol/sdk/types.js";
+import { HTTP_ENDPOINT, HTTP_HOST, startHttpTransport } from "./http.js";
+
+const TOKEN = "test-token";
+const PROTOCOL_VERSION = "2025-03-26";
+
+const INITIALIZE = {
+  jsonrpc: "2.0",
+  id: 1,
+  method: "initialize",
+  params: {
+    protocolVersion: PROTOCOL_VERSION,
+    capabilities: {},
+    clientInfo: { name: "http-test", version: "1.0.0" },
+  },
+};
+
+const LIST_TOOLS = { jsonrpc: "2.0", id: 2, method: "tools/list", params: {} };
+
+function createTestServer(): Server {
+  const server = new Server({ name: "http-test", version: "1.0.0" }, { capabilities: { tools: {} } });
+  server.setRequestHandler(ListToolsRequestSchema, async () => ({
+    tools: [{ name: "echo", inputSchema: { type: "object" as const } }],
+  }));
+  return server;
+}
+
+/**
+ * Read a JSON-RPC message from a JSON or SSE response
+ */
+async function readMessage(response: Response): Promise<{ result?: unknown; error?: unknown }> {
+  const text = await response.text();
+  if (!response.headers.get("content-type")?.includes("text/event-stream")) {
+    return JSON.parse(text) as { result?: unknown; error?: unknown };
+  }
+  const data = text
+    .split("\n")
+    .filter((line) => line.startsWith("data: "))
+    .pop();
+  return JSON.parse(data!.slice("data: ".length)) as { result?: unknown; error?: unknown };
+}
+
+describe("Streamable HTTP Transport", () => {
+  let httpServer: http.Server;
+  let url: string;
+
+  const post = (body: unknown, headers: Record<string, string> = {}) =>
+    fetch(url, {
+      method: "POST",
+      headers: {
+        Authorization: `Bearer ${TOKEN}`,
+        "Content-Type": "application/json",
+        Accept: "application/json, text/event-stream",
+        ...headers,
+      },
+      body: typeof body === "string" ? body : JSON.stringify(body),
+    });
+
+  const initialize = async (): Promise<string> => {
+    const response = await post(INITIALIZE);
+    expect(response.status).toBe(200);
+    await readMessage(response);
+    return response.headers.get("mcp-session-id")!;
+  };
+
+  const listTools = (sessionId: string) =>
+    post(LIST_TOOLS, { "mcp-session-id": sessionId, "mcp-protocol-version": PROTOCOL_VERSION });
+
+  beforeAll(async () => {
+    httpServer = await startHttpTransport(createTestServer(), { token: TOKEN, port: 0 });
+    url = `http://${HTTP_HOST}:${(httpServer.address() as AddressInfo).port}${HTTP_ENDPOINT}`;
+  });
+
+  afterAll(() => {
+    httpServer.closeAllConnections();
+    httpServer.close();
+  });
+
+  describe("request guards", () => {
+    it("should reject missing and wrong tokens", async () => {
+      const missing = await fetch(url, { method: "POST", body: JSON.stringify(INITIALIZE) });
+      expect(missing.status).toBe(401);
+      expect(missing.headers.get("www-authenticate")).toBe("Bearer");
+
+      const wrong = await post(INITIALIZE, { Authorization: "Bearer wrong-token" });
+      expect(wrong.status).toBe(401);
+    });
+
+    it("should only serve the MCP endpoint", async () => {
+      const response = await fetch(url.replace(HTTP_ENDPOINT, "/other"), {
+        headers: { Authorization: `Bearer ${TOKEN}` },
+      });
+      expect(response.status).toBe(404);
+    });
+
+    it("should reject invalid and oversized bodies", async () => {
+      const invalid = await post("{not json");
+      expect(invalid.status).toBe(400);
+      expect((await readMessage(invalid)).error).toMatchObject({ message: "Invalid JSON body" });
+
+      const oversized = await post(JSON.stringify({ padding: "x".repeat(5 * 1024 * 1024) }));
+      expect(oversized.status).toBe(400);
+      expect((await readMessage(oversized)).error).toMatchObject({
+        message: "Request body too large",
+      });
+    });
+  });
+
+  describe("sessions", () => {
+    it("should initialize a session and list tools", async () => {
+      const sessionId = await initialize();
+      expect(sessionId).toBeTruthy();
+
+      const response = await listTools(sessionId);
+      expect(response.status).toBe(200);
+      expect((await readMessage(response)).result).toMatchObject({ tools: [{ name: "echo" }] });
+    });
+
+    it("should replace the previous session on a new initialize", async () => {
+      const first = await initialize();
+      const second = await initialize();
+      expect(second).not.toBe(first);
+
+      expect((await listTools(first)).status).toBe(404);
+      expect((await listTools(second)).status).toBe(200);
+    });
+  });
+});
@@ -0,0 +1,152 @@
+/**
+ * Streamable HTTP Transport
+ *
+ * Serves the MCP server over streamable HTTP on localhost, guarded by a
+ * bearer token. The tool registry and dynamic loader are process-wide,
+ * so one client session is served at a time: a new initialize request
+ * replaces the previous session.
+ */
+
+import { randomUUID, timingSafeEqual } from "crypto";
+import * as http from "http";
+import type { Server } from "@modelcontextprotocol/sdk/server/index.js";
+import { StreamableHTTPServerTransport } from "@modelcontextprotocol/sdk/server/streamableHttp.js";
+import { isInitializeRequest } from "@modelcontextprotocol/sdk/types.js";
+
+export const DEFAULT_HTTP_PORT = 3100;
+export const HTTP_HOST = "127.0.0.1";
+export const HTTP_ENDPOINT = "/mcp";
+
+const MAX_BODY_BYTES = 4 * 1024 * 1024;
+
+export interface HttpTransportOptions {
+  /** Bearer token clients must send in the Authorization header */
+  token: string;
+  /** Port to listen on (default: 3100) */
+  port?: number;
+  verbose?: boolean;
+}
+
+/**
+ * Check the Authorization header against the expected token
+ */
+function isAuthorized(req: http.IncomingMessage, token: string): boolean {
+  const match = (req.headers.authorization ?? "").match(/^Bearer\s+(.+)$/i);
+  if (!match?.[1]) return false;
+
+  const given = Buffer.from(match[1]);
+  const expected = Buffer.from(token);
+  return given.length === expected.length && timingSafeEqual(given, expected);
+}
+
+/**
+ * Read and parse a JSON request body
+ */
+function readJsonBody(req: http.IncomingMessage): Promise<unknown> {
+  return new Promise((resolve, reject) => {
+    const chunks: Buffer[] = [];
+    let size = 0;
+
+    req.on("data", (chunk: Buffer) => {
+      size += chunk.length;
+      if (size > MAX_BODY_BYTES) {
+        // Drain the rest so the error response can still be sent
+        req.removeAllListeners("data");
+        req.resume();
+        reject(new Error("Request body too large"));
+        return;
+      }
+      chunks.push(chunk);
+    });
+    req.on("end", () => {
+      try {
+        resolve(JSON.parse(Buffer.concat(chunks).toString("utf-8")));
+      } catch {
+        reject(new Error("Invalid JSON body"));
+      }
+    });
+    req.on("error", reject);
+  });
+}
+
+function sendError(
+  res: http.ServerResponse,
+  status: number,
+  code: number,
+  message: string,
+  headers: Record<string, string> = {}
+): void {
+  res.writeHead(status, { "Content-Type": "application/json", ...headers });
+  res.end(JSON.stringify({ jsonrpc: "2.0", error: { code, message }, id: null }));
+}
+
+/**
+ * Start serving an MCP server over streamable HTTP
+ */
+export async function startHttpTransport(
+  server: Server,
+  options: HttpTransportOptions
+): Promise<http.Server> {
+  let current: StreamableHTTPServerTransport | null = null;
+
+  const openSession = async (): Promise<StreamableHTTPServerTransport> => {
+    if (current) {
+      if (options.verbose) console.error("[distill] Replacing previous HTTP session");
+      await current.close();
+    }
+
+    const transport = new StreamableHTTPServerTransport({
+      sessionIdGenerator: () => randomUUID(),
+    });
+    transport.onclose = () => {
+      if (current === transport) current = null;
+    };
+    await server.connect(transport);
+    current = transport;
+    return transport;
+  };
+
+  const httpServer = http.createServer(async (req, res) => {
+    try {
+      const url = new URL(req.url ?? "/", `http://${HTTP_HOST}`);
+      if (url.pathname !== HTTP_ENDPOINT) {
+        sendError(res, 404, -32000, "Not found");
+        return;
+      }
+
+      if (!isAuthorized(req, options.token)) {
+        sendError(res, 401, -32001, "Unauthorized", { "WWW-Authenticate": "Bearer" });
+        return;
+      }
+
+      let body: unknown;
+      if (req.method === "POST") {
+        try {
+          body = await readJsonBody(req);
+        } catch (error) {
+          sendError(res, 400, -32700, error instanceof Error ? error.message : "Parse error");
+          return;
+        }
+      }
+
+      const transport =
+        req.method === "POST" && isInitializeRequest(body) ? await openSession() : current;
+      if (!transport) {
+        sendError(res, 400, -32000, "No active session; send an initialize request first");
+        return;
+      }
+
+      await transport.handleRequest(req, res, body);
+    } catch (error) {
+      if (options.verbose) console.error("[distill] HTTP request failed:", error);
+      if (!res.headersSent) sendError(res, 500, -32603, "Internal error");
+    }
+  });
+
+  await new Promise<void>((resolve, reject) => {
+    httpServer.once("error", reject);
+    httpServer.listen(options.port ?? DEFAULT_HTTP_PORT, HTTP_HOST, () => resolve());
+  });
+
+  return httpServer;
+}
@@ -0,0 +1,73 @@
+/**
+ * Atomic Write Tests
+ */
+
+import { describe, it, expect, beforeEach, afterEach } from "vitest";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { writeFileAtomic } from "./atomic-write.js";
+
+const isWindows = process.platform === "win32";
+
+describe("writeFileAtomic", () => {
+  let dir: string;
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "atomic-write-"));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("should create the file and its parent directories", () => {
+    const file = path.join(dir, "nested", "config.json");
+    writeFileAtomic(file, '{"a":1}\n');
+    expect(fs.readFileSync(file, "utf-8")).toBe('{"a":1}\n');
+  });
+
+  it("should replace existing content without leaving temporary files", () => {
+    const file = path.join(dir, "config.json");
+    fs.writeFileSync(file, "old content that is longer than the new one");
+    writeFileAtomic(file, "new");
+
+    expect(fs.readFileSync(file, "utf-8")).toBe("new");
+    expect(fs.readdirSync(dir)).toEqual(["config.json"]);
+  });
+
+  it("should leave the original untouched when the write fails", () => {
+    const file = path.join(dir, "config.json");
+    fs.writeFileSync(file, "original");
+    // A directory at the target path makes the final rename fail
+    const blocked = path.join(dir, "blocked");
+    fs.mkdirSync(path.join(blocked, "child"), { recursive: true });
+
+    expect(() => writeFileAtomic(blocked, "data")).toThrow();
+    expect(fs.readFileSync(file, "utf-8")).toBe("original");
+    expect(fs.readdirSync(dir).sort()).toEqual(["blocked", "config.json"]);
+  });
+
+  it.skipIf(isWindows)("should keep the mode of existing files", () => {
+    const file = path.join(dir, "secrets.json");
+    fs.writeFileSync(file, "{}");
+    fs.chmodSync(file, 0o600);
+    writeFileAtomic(file, '{"token":"x"}');
+    expect(fs.statSync(file).mode & 0o777).toBe(0o600);
+
+    const script = path.join(dir, "hook.sh");
+    writeFileAtomic(script, "#!/bin/sh\n", { mode: 0o755 });
+    expect(fs.statSync(script).mode & 0o777).toBe(0o755);
+  });
+
+  it.skipIf(isWindows)("should write through symlinks", () => {
+    const real = path.join(dir, "real.json");
+    const link = path.join(dir, "link.json");
+    fs.writeFileSync(real, "old");
+    fs.symlinkSync(real, link);
+
+    writeFileAtomic(link, "new");
+    expect(fs.lstatSync(link).isSymbolicLink()).toBe(true);
+    expect(fs.readFileSync(real, "utf-8")).toBe("new");
+  });
+});
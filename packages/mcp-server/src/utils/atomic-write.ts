@@ -0,0 +1,86 @@
+/**
+ * Atomic Write
+ *
+ * Crash-safe file writes: data goes to a temporary file in the same
+ * directory, is flushed to disk, then renamed over the target, so readers
+ * see either the old or the new content and never a truncated file.
+ * Used for the IDE configs and hook files written by the CLI.
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+import { randomBytes } from "crypto";
+
+export interface AtomicWriteOptions {
+  /** File mode (default: the existing file's mode, or 0o666 minus umask) */
+  mode?: number;
+}
+
+/**
+ * Resolve symlinks so the link itself is not replaced by a regular file
+ */
+function resolveTarget(filePath: string): string {
+  try {
+    return fs.realpathSync(filePath);
+  } catch {
+    return filePath;
+  }
+}
+
+/**
+ * Flush a directory entry (rename durability); not supported on Windows
+ */
+function fsyncDirectory(dir: string): void {
+  if (process.platform === "win32") return;
+  try {
+    const fd = fs.openSync(dir, "r");
+    try {
+      fs.fsyncSync(fd);
+    } finally {
+      fs.closeSync(fd);
+    }
+  } catch {
+    // Best effort: some filesystems refuse fsync on directories
+  }
+}
+
+/**
+ * Write a file atomically, creating parent directories as needed
+ */
+export function writeFileAtomic(
+  filePath: string,
+  data: string | Buffer,
+  options: AtomicWriteOptions = {}
+): void {
+  const target = resolveTarget(filePath);
+  const dir = path.dirname(target);
+  fs.mkdirSync(dir, { recursive: true });
+
+  let mode = options.mode;
+  if (mode === undefined) {
+    try {
+      mode = fs.statSync(target).mode & 0o7777;
+    } catch {
+      // New file
+    }
+  }
+
+  const tmpPath = path.join(dir, `.${path.basename(target)}.${randomBytes(6).toString("hex")}.tmp`);
+  try {
+    const fd = fs.openSync(tmpPath, "wx", mode ?? 0o666);
+    try {
+      fs.writeFileSync(fd, data);
+      fs.fsyncSync(fd);
+    } finally {
+      fs.closeSync(fd);
+    }
+    // openSync applies the umask; keep explicit and existing modes exact
+    if (mode !== undefined) fs.chmodSync(tmpPath, mode);
+    fs.renameSync(tmpPath, target);
+  } catch (err) {
+    fs.rmSync(tmpPath, { force: true });
+    throw err;
+  }
+
+  fsyncDirectory(dir);
+}
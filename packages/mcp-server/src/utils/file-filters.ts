@@ -0,0 +1,63 @@
+/**
+ * File Filters
+ *
+ * Directory and extension filters shared by the tools that walk
+ * the working directory (project_map, grep_context).
+ */
+
+import * as path from "path";
+import type { IgnoreMatcher } from "./ignore-rules.js";
+
+// Directories that never carry useful context
+export const SKIP_DIRS = new Set([
+  "node_modules",
+  "dist",
+  "build",
+  "out",
+  "target",
+  "coverage",
+  "vendor",
+  "__pycache__",
+]);
+
+// Files that carry no readable context
+export const BINARY_EXTENSIONS = new Set([
+  ".png", ".jpg", ".jpeg", ".gif", ".webp", ".ico", ".bmp", ".svgz",
+  ".woff", ".woff2", ".ttf", ".otf", ".eot",
+  ".zip", ".gz", ".tar", ".tgz", ".7z", ".rar",
+  ".pdf", ".mp3", ".mp4", ".mov", ".wav",
+  ".exe", ".dll", ".so", ".dylib", ".node", ".wasm", ".rlib", ".class", ".jar", ".pyc",
+  ".lockb", ".db", ".sqlite",
+]);
+
+/**
+ * Whether a directory should be skipped while walking
+ */
+export function isSkippedDir(name: string): boolean {
+  return name.startsWith(".") || SKIP_DIRS.has(name);
+}
+
+/**
+ * Whether a file should be skipped while walking
+ */
+export function isSkippedFile(name: string): boolean {
+  return name.startsWith(".") || BINARY_EXTENSIONS.has(path.extname(name).toLowerCase());
+}
+
+/**
+ * Whether a directory is excluded from a walk
+ *
+ * Ignore rules take precedence over the defaults above, so `!build/`
+ * brings build output back; .git is always skipped.
+ */
+export function isExcludedDir(name: string, relPath: string, ignore: IgnoreMatcher): boolean {
+  if (name === ".git") return true;
+  return ignore.match(relPath, true) ?? isSkippedDir(name);
+}
+
+/**
+ * Whether a file is excluded from a walk, with ignore rules taking precedence
+ */
+export function isExcludedFile(name: string, relPath: string, ignore: IgnoreMatcher): boolean {
+  return ignore.match(relPath, false) ?? isSkippedFile(name);
+}
@@ -0,0 +1,154 @@
+/**
+ * Ignore Rules Tests
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from "vitest";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { IgnoreMatcher, globToRegExp, parseIgnoreRules } from "./ignore-rules.js";
+
+function matcher(content: string, baseDir: string = ""): IgnoreMatcher {
+  return IgnoreMatcher.fromRules(parseIgnoreRules(content, baseDir));
+}
+
+describe("ignore rules", () => {
+  describe("globToRegExp", () => {
+    it("should expand braces only when enabled", () => {
+      const glob = globToRegExp("src/**/*.{js,jsx}", { braces: true });
+      expect(glob.test("src/a.js")).toBe(true);
+      expect(glob.test("src/ui/b.jsx")).toBe(true);
+      expect(glob.test("lib/a.js")).toBe(false);
+      expect(globToRegExp("{a,b}.txt").test("{a,b}.txt")).toBe(true);
+    });
+
+    it("should match at any depth when requested", () => {
+      expect(globToRegExp("*.ts").test("src/a.ts")).toBe(false);
+      expect(globToRegExp("*.ts", { anyDepth: true }).test("src/a.ts")).toBe(true);
+    });
+  });
+
+  describe("parseIgnoreRules", () => {
+    it("should skip blank lines and comments", () => {
+      expect(parseIgnoreRules("# comment\n\n  \n*.log\n")).toHaveLength(1);
+    });
+
+    it("should skip invalid patterns and keep the rest", () => {
+      const ignore = matcher("[z-a]\n*.log\n");
+      expect(ignore.isIgnored("debug.log", false)).toBe(true);
+      expect(ignore.isIgnored("z", false)).toBe(false);
+    });
+
+    it("should unescape leading # and !", () => {
+      const ignore = matcher("\\#notes\n\\!important\n");
+      expect(ignore.isIgnored("#notes", false)).toBe(true);
+      expect(ignore.isIgnored("!important", false)).toBe(true);
+    });
+  });
+
+  describe("IgnoreMatcher", () => {
+    it("should match patterns without a slash at any depth", () => {
+      const ignore = matcher("*.log\nfixtures\n");
+      expect(ignore.isIgnored("debug.log", false)).toBe(true);
+      expect(ignore.isIgnored("src/deep/debug.log", false)).toBe(true);
+      expect(ignore.isIgnored("tests/fixtures", true)).toBe(true);
+      expect(ignore.isIgnored("src/log.ts", false)).toBe(false);
+    });
+
+    it("should anchor patterns containing a slash", () => {
+      const ignore = matcher("/generated\nsrc/*.gen.ts\n");
+      expect(ignore.isIgnored("generated", true)).toBe(true);
+      expect(ignore.isIgnored("src/generated", true)).toBe(false);
+      expect(ignore.isIgnored("src/api.gen.ts", false)).toBe(true);
+      expect(ignore.isIgnored("src/nested/api.gen.ts", false)).toBe(false);
+    });
+
+    it("should support ** and character classes", () => {
+      const ignore = matcher("**/snapshots/**\ndocs/**/*.md\nlog[0-9].txt\n");
+      expect(ignore.isIgnored("a/b/snapshots/x.snap", false)).toBe(true);
+      expect(ignore.isIgnored("docs/intro.md", false)).toBe(true);
+      expect(ignore.isIgnored("docs/api/v1/ref.md", false)).toBe(true);
+      expect(ignore.isIgnored("log7.txt", false)).toBe(true);
+      expect(ignore.isIgnored("logs.txt", false)).toBe(false);
+    });
+
+    it("should only match directories with a trailing slash", () => {
+      const ignore = matcher("cache/\n");
+      expect(ignore.isIgnored("cache", true)).toBe(true);
+      expect(ignore.isIgnored("cache", false)).toBe(false);
+    });
+
+    it("should let the last matching rule win", () => {
+      const ignore = matcher("*.ts\n!keep.ts\n");
+      expect(ignore.isIgnored("src/a.ts", false)).toBe(true);
+      expect(ignore.isIgnored("src/keep.ts", false)).toBe(false);
+    });
+
+    it("should scope rules to the directory of their ignore file", () => {
+      const ignore = matcher("/out\n*.tmp\n", "packages/app");
+      expect(ignore.isIgnored("packages/app/out", true)).toBe(true);
+      expect(ignore.isIgnored("packages/app/src/x.tmp", false)).toBe(true);
+      expect(ignore.isIgnored("out", true)).toBe(false);
+      expect(ignore.isIgnored("packages/lib/x.tmp", false)).toBe(false);
+    });
+  });
+
+  describe("forDirectory", () => {
+    let dir: string;
+
+    beforeAll(() => {
+      dir = fs.mkdtempSync(path.join(os.tmpdir(), "ignore-rules-"));
+      fs.writeFileSync(path.join(dir, ".gitignore"), "*.snap\n");
+      fs.writeFileSync(path.join(dir, ".ctxoptignore"), "fixtures/\n!keep.snap\n");
+      fs.mkdirSync(path.join(dir, "src"));
+    });
+
+    afterAll(() => {
+      fs.rmSync(dir, { recursive: true, force: true });
+    });
+
+    it("should load .gitignore then .ctxoptignore", () => {
+      const ignore = IgnoreMatcher.empty().forDirectory(dir, "");
+      expect(ignore.isIgnored("a.snap", false)).toBe(true);
+      expect(ignore.isIgnored("fixtures", true)).toBe(true);
+      expect(ignore.isIgnored("keep.snap", false)).toBe(false);
+    });
+
+    it("should return the same matcher when a directory has no ignore files", () => {
+      const ignore = IgnoreMatcher.empty();
+      expect(ignore.forDirectory(path.join(dir, "src"), "src")).toBe(ignore);
+    });
+  });
+
+  describe("forRoot", () => {
+    let repo: string;
+
+    beforeAll(() => {
+      repo = fs.mkdtempSync(path.join(os.tmpdir(), "ignore-rules-"));
+      fs.mkdirSync(path.join(repo, ".git", "info"), { recursive: true });
+      fs.writeFileSync(path.join(repo, ".git", "info", "exclude"), "*.local\n");
+      fs.writeFileSync(path.join(repo, ".gitignore"), "*.log\n/build\npackages/api/gen/\n");
+      fs.mkdirSync(path.join(repo, "packages", "api"), { recursive: true });
+      fs.writeFileSync(path.join(repo, "packages", ".ctxoptignore"), "!keep.log\n");
+    });
+
+    afterAll(() => {
+      fs.rmSync(repo, { recursive: true, force: true });
+    });
+
+    it("should apply .git/info/exclude and the ignore files above a subdirectory", () => {
+      const ignore = IgnoreMatcher.forRoot(path.join(repo, "packages", "api"));
+      expect(ignore.isIgnored("src/notes.local", false)).toBe(true);
+      expect(ignore.isIgnored("debug.log", false)).toBe(true);
+      expect(ignore.isIgnored("gen", true)).toBe(true);
+      expect(ignore.isIgnored("keep.log", false)).toBe(false);
+      expect(ignore.isIgnored("build", true)).toBe(false);
+    });
+
+    it("should leave the walk root's own ignore files to forDirectory", () => {
+      const ignore = IgnoreMatcher.forRoot(repo);
+      expect(ignore.isIgnored("notes.local", false)).toBe(true);
+      expect(ignore.isIgnored("debug.log", false)).toBe(false);
+    });
+  });
+});
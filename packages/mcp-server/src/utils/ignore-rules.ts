@@ -0,0 +1,256 @@
+/**
+ * Ignore Rules
+ *
+ * Gitignore-style exclusions shared by the directory walkers
+ * (project_map, grep_context, analyze). Rules come from `.gitignore`
+ * and `.ctxoptignore` files in the walked directories and their parents
+ * up to the git root, plus `.git/info/exclude`; `.ctxoptignore`
+ * lists files that are tracked but should not be offered to the model
+ * (fixtures, generated code, snapshots).
+ */
+
+import * as fs from "fs";
+import * as path from "path";
+
+// Read in this order, so .ctxoptignore can re-include with "!pattern"
+export const IGNORE_FILES = [".gitignore", ".ctxoptignore"];
+
+export interface IgnoreRule {
+  regex: RegExp;
+  negate: boolean;
+  /** Pattern ended with "/" and only matches directories */
+  dirOnly: boolean;
+  /** Directory of the ignore file, relative to the walk root ("" for the root) */
+  baseDir: string;
+  /** For ignore files above the walk root: the walk root relative to their directory */
+  prefix?: string;
+}
+
+export interface GlobOptions {
+  /** Expand {a,b} alternatives (not part of gitignore syntax) */
+  braces?: boolean;
+  /** Match at any depth, as gitignore does for patterns without a slash */
+  anyDepth?: boolean;
+}
+
+function globSource(glob: string, braces: boolean): string {
+  let source = "";
+  for (let i = 0; i < glob.length; i++) {
+    const c = glob[i]!;
+    if (c === "*") {
+      if (glob[i + 1] === "*") {
+        // "**/" matches zero or more directories, a trailing "**" everything inside
+        if (glob[i + 2] === "/") {
+          source += "(?:.*/)?";
+          i += 2;
+        } else {
+          source += ".*";
+          i++;
+        }
+      } else {
+        source += "[^/]*";
+      }
+    } else if (c === "?") {
+      source += "[^/]";
+    } else if (c === "[") {
+      const end = glob.indexOf("]", i + 2);
+      if (end === -1) {
+        source += "\\[";
+      } else {
+        const body = glob.slice(i + 1, end).replace(/\\/g, "\\\\");
+        source += body.startsWith("!") ? `[^${body.slice(1)}]` : `[${body}]`;
+        i = end;
+      }
+    } else if (c === "{" && braces && glob.indexOf("}", i) !== -1) {
+      const end = glob.indexOf("}", i);
+      const alternatives = glob.slice(i + 1, end).split(",");
+      source += `(?:${alternatives.map((alt) => globSource(alt, false)).join("|")})`;
+      i = end;
+    } else if (c === "\\" && i + 1 < glob.length) {
+      source += glob[++i]!.replace(/[.*+?^${}()|[\]\\/]/g, "\\$&");
+    } else {
+      source += c.replace(/[.+^${}()|[\]\\]/g, "\\$&");
+    }
+  }
+  return source;
+}
+
+/**
+ * Compile a glob to a regex over "/"-separated relative paths
+ * Shared by the ignore rules and the tools' glob filters.
+ */
+export function globToRegExp(glob: string, options: GlobOptions = {}): RegExp {
+  const source = globSource(glob, options.braces ?? false);
+  return new RegExp(options.anyDepth ? `^(?:.*/)?${source}$` : `^${source}$`);
+}
+
+/**
+ * Parse the contents of an ignore file
+ */
+export function parseIgnoreRules(content: string, baseDir: string = ""): IgnoreRule[] {
+  const rules: IgnoreRule[] = [];
+
+  for (const rawLine of content.split(/\r?\n/)) {
+    let line = rawLine.endsWith("\\ ") ? rawLine : rawLine.trimEnd();
+    if (!line || line.startsWith("#")) continue;
+
+    let negate = false;
+    if (line.startsWith("!")) {
+      negate = true;
+      line = line.slice(1);
+    } else if (line.startsWith("\\!") || line.startsWith("\\#")) {
+      line = line.slice(1);
+    }
+
+    const dirOnly = line.endsWith("/");
+    if (dirOnly) line = line.slice(0, -1);
+
+    // A slash anywhere but the end anchors the pattern to the ignore file's directory
+    const anchored = line.includes("/");
+    if (line.startsWith("/")) line = line.slice(1);
+    if (!line) continue;
+
+    // Patterns without a slash match at any depth below the ignore file
+    let regex: RegExp;
+    try {
+      regex = globToRegExp(line, { anyDepth: !anchored });
+    } catch {
+      // Invalid pattern (e.g. "[z-a]"): skipped, as git does
+      continue;
+    }
+    rules.push({ regex, negate, dirOnly, baseDir });
+  }
+
+  return rules;
+}
+
+function readIgnoreFile(filePath: string): string | null {
+  try {
+    return fs.readFileSync(filePath, "utf-8");
+  } catch {
+    return null;
+  }
+}
+
+/**
+ * Nearest directory at or above dir containing .git, or null
+ */
+function findGitRoot(dir: string): string | null {
+  for (let current = dir; ; current = path.dirname(current)) {
+    if (fs.existsSync(path.join(current, ".git"))) return current;
+    if (path.dirname(current) === current) return null;
+  }
+}
+
+/**
+ * Read .git/info/exclude, following the "gitdir:" file of worktrees and submodules
+ */
+function readGitExclude(gitRoot: string): string | null {
+  let gitDir = path.join(gitRoot, ".git");
+  try {
+    if (fs.statSync(gitDir).isFile()) {
+      const target = fs.readFileSync(gitDir, "utf-8").match(/^gitdir:\s*(.+)$/m)?.[1];
+      if (!target) return null;
+      gitDir = path.resolve(gitRoot, target.trim());
+      // Linked worktrees share info/exclude with the main repository
+      const commonDir = readIgnoreFile(path.join(gitDir, "commondir"));
+      if (commonDir) gitDir = path.resolve(gitDir, commonDir.trim());
+    }
+  } catch {
+    return null;
+  }
+  return readIgnoreFile(path.join(gitDir, "info", "exclude"));
+}
+
+/**
+ * Ignore rules in effect for one directory of a walk
+ *
+ * Walkers call forDirectory() on each directory they enter and skip the
+ * entries isIgnored() reports; an ignored directory is not descended,
+ * so its contents cannot be re-included (same as git).
+ */
+export class IgnoreMatcher {
+  private constructor(private readonly rules: IgnoreRule[]) {}
+
+  static empty(): IgnoreMatcher {
+    return new IgnoreMatcher([]);
+  }
+
+  static fromRules(rules: IgnoreRule[]): IgnoreMatcher {
+    return new IgnoreMatcher(rules);
+  }
+
+  /**
+   * Rules that apply to a walk root from above it
+   *
+   * Inside a git repository these are .git/info/exclude and the ignore
+   * files of every directory from the git root down to the walk root's
+   * parent; the walk root's own files are added by forDirectory().
+   */
+  static forRoot(absRoot: string): IgnoreMatcher {
+    const root = path.resolve(absRoot);
+    const gitRoot = findGitRoot(root);
+    if (!gitRoot) return IgnoreMatcher.empty();
+
+    const rules: IgnoreRule[] = [];
+    const addRules = (content: string | null, dir: string) => {
+      if (content === null) return;
+      const prefix = path.relative(dir, root).split(path.sep).join("/");
+      rules.push(...parseIgnoreRules(content).map((rule) => ({ ...rule, prefix })));
+    };
+
+    addRules(readGitExclude(gitRoot), gitRoot);
+
+    const ancestors: string[] = [];
+    for (let dir = root; dir !== gitRoot; ) {
+      dir = path.dirname(dir);
+      ancestors.unshift(dir);
+    }
+    for (const dir of ancestors) {
+      for (const name of IGNORE_FILES) addRules(readIgnoreFile(path.join(dir, name)), dir);
+    }
+
+    return new IgnoreMatcher(rules);
+  }
+
+  /**
+   * Add the rules of the ignore files in a directory
+   */
+  forDirectory(absDir: string, relDir: string): IgnoreMatcher {
+    const added: IgnoreRule[] = [];
+    for (const name of IGNORE_FILES) {
+      const content = readIgnoreFile(path.join(absDir, name));
+      if (content !== null) added.push(...parseIgnoreRules(content, relDir));
+    }
+    return added.length > 0 ? new IgnoreMatcher([...this.rules, ...added]) : this;
+  }
+
+  /**
+   * Whether a path relative to the walk root is excluded
+   */
+  isIgnored(relPath: string, isDir: boolean): boolean {
+    return this.match(relPath, isDir) ?? false;
+  }
+
+  /**
+   * Verdict of the last matching rule: true when excluded, false when
+   * re-included by a negation, undefined when no rule matches
+   */
+  match(relPath: string, isDir: boolean): boolean | undefined {
+    // Last matching rule wins
+    for (let i = this.rules.length - 1; i >= 0; i--) {
+      const rule = this.rules[i]!;
+      if (rule.dirOnly && !isDir) continue;
+
+      let subPath = relPath;
+      if (rule.baseDir) {
+        if (!relPath.startsWith(`${rule.baseDir}/`)) continue;
+        subPath = relPath.slice(rule.baseDir.length + 1);
+      }
+      if (rule.prefix) subPath = `${rule.prefix}/${subPath}`;
+
+      if (rule.regex.test(subPath)) return !rule.negate;
+    }
+    return undefined;
+  }
+}
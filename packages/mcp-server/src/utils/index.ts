@@ -10,3 +10,10 @@ export * from "./token-counter.js";
 export * from "./bm25.js";
 export * from "./embeddings.js";
 export * from "./hybrid-search.js";
+export * from "./line-diff.js";
+export * from "./file-filters.js";
+export * from "./text-encoding.js";
+export * from "./path-normalizer.js";
+export * from "./safe-file-reader.js";
+export * from "./ignore-rules.js";
+export * from "./atomic-write.js";
@@ -0,0 +1,76 @@
+/**
+ * Line Diff Tests
+ */
+
+import { describe, it, expect } from "vitest";
+import { diffLines, diffLineOps, formatUnifiedDiff } from "./line-diff.js";
+
+const numbered = (count: number) => Array.from({ length: count }, (_, i) => `line${i}`);
+
+describe("line diff", () => {
+  describe("diffLineOps", () => {
+    it("should reconstruct both sides from the operations", () => {
+      const oldLines = ["a", "b", "c", "a", "b", "b", "a"];
+      const newLines = ["c", "b", "a", "b", "a", "c"];
+      const ops = diffLineOps(oldLines, newLines);
+
+      expect(ops).not.toBeNull();
+      expect(ops!.filter((op) => op.type !== "insert").map((op) => op.text)).toEqual(oldLines);
+      expect(ops!.filter((op) => op.type !== "delete").map((op) => op.text)).toEqual(newLines);
+    });
+
+    it("should find the minimal edit script", () => {
+      const ops = diffLineOps(["a", "b", "c", "a", "b", "b", "a"], ["c", "b", "a", "b", "a", "c"]);
+      const edits = ops!.filter((op) => op.type !== "equal");
+      expect(edits).toHaveLength(5);
+    });
+
+    it("should give up past the edit distance limit", () => {
+      const ops = diffLineOps(numbered(50), numbered(50).map((l) => `${l}!`), 10);
+      expect(ops).toBeNull();
+    });
+  });
+
+  describe("diffLines", () => {
+    it("should return no hunks for identical text", () => {
+      const result = diffLines("a\nb\n", "a\nb\n");
+      expect(result).toEqual({ hunks: [], additions: 0, deletions: 0 });
+    });
+
+    it("should treat CRLF and LF as equal", () => {
+      const result = diffLines("a\r\nb\r\n", "a\nb\n");
+      expect(result?.hunks).toHaveLength(0);
+    });
+
+    it("should keep distant changes in separate hunks", () => {
+      const oldText = numbered(30).join("\n");
+      const newText = oldText.replace("line5", "line5 changed").replace("line25\n", "");
+      const result = diffLines(oldText, newText);
+
+      expect(result?.additions).toBe(1);
+      expect(result?.deletions).toBe(2);
+      expect(result?.hunks).toHaveLength(2);
+      expect(result?.hunks[0]).toMatchObject({ oldStart: 3, oldLines: 7, newStart: 3, newLines: 7 });
+    });
+
+    it("should merge nearby changes into one hunk", () => {
+      const oldText = numbered(20).join("\n");
+      const newText = oldText.replace("line5", "x").replace("line9", "y");
+      expect(diffLines(oldText, newText)?.hunks).toHaveLength(1);
+    });
+
+    it("should respect the context option", () => {
+      const oldText = numbered(20).join("\n");
+      const newText = oldText.replace("line10", "changed");
+      const hunk = diffLines(oldText, newText, { context: 1 })?.hunks[0];
+      expect(hunk?.lines).toEqual([" line9", "-line10", "+changed", " line11"]);
+    });
+  });
+
+  describe("formatUnifiedDiff", () => {
+    it("should render headers and hunk ranges", () => {
+      const result = diffLines("", "x\ny\n")!;
+      expect(formatUnifiedDiff(result, "a/f", "b/f")).toBe("--- a/f\n+++ b/f\n@@ -0,0 +1,2 @@\n+x\n+y");
+    });
+  });
+});
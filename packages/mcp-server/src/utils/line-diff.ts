@@ -0,0 +1,282 @@
+/**
+ * Line Diff
+ *
+ * Myers O(ND) line diff with unified-format hunks.
+ * Used to return only what changed in a file since it was last read.
+ */
+
+export type DiffOpType = "equal" | "insert" | "delete";
+
+export interface DiffOp {
+  type: DiffOpType;
+  /** 0-based position in the old file */
+  oldIndex: number;
+  /** 0-based position in the new file */
+  newIndex: number;
+  text: string;
+}
+
+export interface LineDiffHunk {
+  oldStart: number;
+  oldLines: number;
+  newStart: number;
+  newLines: number;
+  /** Lines prefixed with " ", "+" or "-" */
+  lines: string[];
+}
+
+export interface LineDiffResult {
+  hunks: LineDiffHunk[];
+  additions: number;
+  deletions: number;
+}
+
+export interface LineDiffOptions {
+  /** Unchanged lines shown around each change (default: 3) */
+  context?: number;
+  /** Give up when the edit distance exceeds this (default: 2000) */
+  maxEditDistance?: number;
+}
+
+const DEFAULT_CONTEXT = 3;
+const DEFAULT_MAX_EDIT_DISTANCE = 2000;
+
+/**
+ * Split text into lines, normalizing CRLF
+ */
+function splitLines(text: string): string[] {
+  if (text === "") return [];
+  const lines = text.replace(/\r\n/g, "\n").split("\n");
+  // A trailing newline does not start a new line
+  if (lines[lines.length - 1] === "") lines.pop();
+  return lines;
+}
+
+/**
+ * Shortest edit script between two line arrays (Myers, greedy forward pass)
+ * Returns null when the edit distance exceeds maxD.
+ */
+function myers(a: string[], b: string[], maxD: number): DiffOp[] | null {
+  const n = a.length;
+  const m = b.length;
+  const max = n + m;
+  const offset = max;
+  const v = new Int32Array(2 * max + 2);
+  // Only diagonals -d-1..d+1 are read when backtracking step d, so keep just that window
+  const trace: Array<{ lo: number; v: Int32Array }> = [];
+
+  let found = -1;
+  for (let d = 0; d <= Math.min(max, maxD) && found < 0; d++) {
+    const lo = Math.max(0, offset - d - 1);
+    trace.push({ lo, v: v.slice(lo, offset + d + 2) });
+    for (let k = -d; k <= d; k += 2) {
+      let x: number;
+      if (k === -d || (k !== d && v[offset + k - 1]! < v[offset + k + 1]!)) {
+        x = v[offset + k + 1]!;
+      } else {
+        x = v[offset + k - 1]! + 1;
+      }
+      let y = x - k;
+      while (x < n && y < m && a[x] === b[y]) {
+        x++;
+        y++;
+      }
+      v[offset + k] = x;
+      if (x >= n && y >= m) {
+        found = d;
+        break;
+      }
+    }
+  }
+
+  if (found < 0) return null;
+
+  // Walk the trace backwards to recover the edit script
+  const reversed: DiffOp[] = [];
+  let x = n;
+  let y = m;
+  for (let d = found; d >= 0; d--) {
+    const step = trace[d]!;
+    const at = (diagonal: number) => step.v[offset + diagonal - step.lo]!;
+    const k = x - y;
+    const prevK = k === -d || (k !== d && at(k - 1) < at(k + 1)) ? k + 1 : k - 1;
+    const prevX = at(prevK);
+    const prevY = prevX - prevK;
+
+    while (x > prevX && y > prevY) {
+      reversed.push({ type: "equal", oldIndex: x - 1, newIndex: y - 1, text: a[x - 1]! });
+      x--;
+      y--;
+    }
+    if (d > 0) {
+      if (x === prevX) {
+        reversed.push({ type: "insert", oldIndex: x, newIndex: y - 1, text: b[y - 1]! });
+      } else {
+        reversed.push({ type: "delete", oldIndex: x - 1, newIndex: y, text: a[x - 1]! });
+      }
+    }
+    x = prevX;
+    y = prevY;
+  }
+
+  return reversed.reverse();
+}
+
+/**
+ * Compute line operations, trimming the common prefix/suffix first
+ */
+export function diffLineOps(
+  oldLines: string[],
+  newLines: string[],
+  maxEditDistance: number = DEFAULT_MAX_EDIT_DISTANCE
+): DiffOp[] | null {
+  let prefix = 0;
+  while (
+    prefix < oldLines.length &&
+    prefix < newLines.length &&
+    oldLines[prefix] === newLines[prefix]
+  ) {
+    prefix++;
+  }
+
+  let suffix = 0;
+  while (
+    suffix < oldLines.length - prefix &&
+    suffix < newLines.length - prefix &&
+    oldLines[oldLines.length - 1 - suffix] === newLines[newLines.length - 1 - suffix]
+  ) {
+    suffix++;
+  }
+
+  const middle = myers(
+    oldLines.slice(prefix, oldLines.length - suffix),
+    newLines.slice(prefix, newLines.length - suffix),
+    maxEditDistance
+  );
+  if (!middle) return null;
+
+  const ops: DiffOp[] = [];
+  for (let i = 0; i < prefix; i++) {
+    ops.push({ type: "equal", oldIndex: i, newIndex: i, text: oldLines[i]! });
+  }
+  for (const op of middle) {
+    ops.push({ ...op, oldIndex: op.oldIndex + prefix, newIndex: op.newIndex + prefix });
+  }
+  for (let i = suffix; i > 0; i--) {
+    ops.push({
+      type: "equal",
+      oldIndex: oldLines.length - i,
+      newIndex: newLines.length - i,
+      text: oldLines[oldLines.length - i]!,
+    });
+  }
+  return ops;
+}
+
+/**
+ * Group operations into hunks with surrounding context
+ */
+export function buildHunks(ops: DiffOp[], context: number = DEFAULT_CONTEXT): LineDiffHunk[] {
+  const hunks: LineDiffHunk[] = [];
+  let i = 0;
+
+  while (i < ops.length) {
+    if (ops[i]!.type === "equal") {
+      i++;
+      continue;
+    }
+
+    // Extend the hunk while gaps between changes fit inside 2x context
+    const start = Math.max(0, i - context);
+    let end = i + 1;
+    let j = i + 1;
+    while (j < ops.length) {
+      if (ops[j]!.type !== "equal") {
+        end = j + 1;
+        j++;
+        continue;
+      }
+      let run = 0;
+      while (j + run < ops.length && ops[j + run]!.type === "equal") run++;
+      if (j + run >= ops.length || run > context * 2) break;
+      j += run;
+    }
+    const stop = Math.min(ops.length, end + context);
+
+    const slice = ops.slice(start, stop);
+    const first = slice[0]!;
+    let oldCount = 0;
+    let newCount = 0;
+    const lines: string[] = [];
+    for (const op of slice) {
+      if (op.type === "equal") {
+        oldCount++;
+        newCount++;
+        lines.push(` ${op.text}`);
+      } else if (op.type === "delete") {
+        oldCount++;
+        lines.push(`-${op.text}`);
+      } else {
+        newCount++;
+        lines.push(`+${op.text}`);
+      }
+    }
+
+    hunks.push({
+      oldStart: oldCount === 0 ? first.oldIndex : first.oldIndex + 1,
+      oldLines: oldCount,
+      newStart: newCount === 0 ? first.newIndex : first.newIndex + 1,
+      newLines: newCount,
+      lines,
+    });
+    i = stop;
+  }
+
+  return hunks;
+}
+
+/**
+ * Diff two texts line by line
+ * Returns null when the files differ too much for a diff to be useful.
+ */
+export function diffLines(
+  oldText: string,
+  newText: string,
+  options: LineDiffOptions = {}
+): LineDiffResult | null {
+  const ops = diffLineOps(
+    splitLines(oldText),
+    splitLines(newText),
+    options.maxEditDistance ?? DEFAULT_MAX_EDIT_DISTANCE
+  );
+  if (!ops) return null;
+
+  let additions = 0;
+  let deletions = 0;
+  for (const op of ops) {
+    if (op.type === "insert") additions++;
+    else if (op.type === "delete") deletions++;
+  }
+
+  return {
+    hunks: buildHunks(ops, options.context ?? DEFAULT_CONTEXT),
+    additions,
+    deletions,
+  };
+}
+
+/**
+ * Render a diff result in unified format
+ */
+export function formatUnifiedDiff(
+  result: LineDiffResult,
+  oldLabel: string,
+  newLabel: string
+): string {
+  const parts: string[] = [`--- ${oldLabel}`, `+++ ${newLabel}`];
+  for (const hunk of result.hunks) {
+    parts.push(`@@ -${hunk.oldStart},${hunk.oldLines} +${hunk.newStart},${hunk.newLines} @@`);
+    parts.push(...hunk.lines);
+  }
+  return parts.join("\n");
+}
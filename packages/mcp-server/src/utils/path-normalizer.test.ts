@@ -0,0 +1,57 @@
+/**
+ * Path Normalizer Tests
+ */
+
+import { describe, it, expect } from "vitest";
+import { isWithinDirectory, normalizeInputPath } from "./path-normalizer.js";
+
+describe("path normalizer", () => {
+  describe("normalizeInputPath", () => {
+    it("should strip surrounding quotes and whitespace", () => {
+      expect(normalizeInputPath(' "src/a.ts" ', "linux")).toBe("src/a.ts");
+    });
+
+    it("should leave POSIX paths alone", () => {
+      expect(normalizeInputPath("/c/Users/a.ts", "linux")).toBe("/c/Users/a.ts");
+      expect(normalizeInputPath("src\\a.ts", "darwin")).toBe("src\\a.ts");
+    });
+
+    describe("on Windows", () => {
+      it("should strip long-path prefixes", () => {
+        expect(normalizeInputPath("\\\\?\\C:\\repo\\src\\a.ts", "win32")).toBe("C:\\repo\\src\\a.ts");
+        expect(normalizeInputPath("\\\\?\\UNC\\server\\share\\a.ts", "win32")).toBe(
+          "\\\\server\\share\\a.ts"
+        );
+      });
+
+      it("should convert MSYS drive paths", () => {
+        expect(normalizeInputPath("/c/Users/dev/repo", "win32")).toBe("C:\\Users\\dev\\repo");
+        expect(normalizeInputPath("/d", "win32")).toBe("D:\\");
+      });
+
+      it("should normalize separators and drive letter case", () => {
+        expect(normalizeInputPath("c:/repo/src/../lib/a.ts", "win32")).toBe("C:\\repo\\lib\\a.ts");
+        expect(normalizeInputPath("src/a.ts", "win32")).toBe("src\\a.ts");
+      });
+    });
+  });
+
+  describe("isWithinDirectory", () => {
+    it("should accept the directory and its children", () => {
+      expect(isWithinDirectory("/repo", "/repo", "linux")).toBe(true);
+      expect(isWithinDirectory("/repo/src/a.ts", "/repo", "linux")).toBe(true);
+      expect(isWithinDirectory("/repo/..hidden", "/repo", "linux")).toBe(true);
+    });
+
+    it("should reject paths outside the directory", () => {
+      expect(isWithinDirectory("/etc/passwd", "/repo", "linux")).toBe(false);
+      expect(isWithinDirectory("/repo-other/a.ts", "/repo", "linux")).toBe(false);
+    });
+
+    it("should compare case-insensitively on Windows", () => {
+      expect(isWithinDirectory("c:\\Repo\\src\\a.ts", "C:\\repo", "win32")).toBe(true);
+      expect(isWithinDirectory("D:\\repo\\a.ts", "C:\\repo", "win32")).toBe(false);
+      expect(isWithinDirectory("C:\\repo2\\a.ts", "C:\\repo", "win32")).toBe(false);
+    });
+  });
+});
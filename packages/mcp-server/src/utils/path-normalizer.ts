@@ -0,0 +1,56 @@
+/**
+ * Path Normalizer
+ *
+ * Normalizes user-supplied paths before validation so Windows inputs
+ * (long-path prefixes, MSYS drive paths, forward slashes, drive letter
+ * case) resolve the same way as native ones.
+ */
+
+import * as path from "path";
+
+function pathApi(platform: NodeJS.Platform): path.PlatformPath {
+  return platform === "win32" ? path.win32 : path.posix;
+}
+
+/**
+ * Normalize a path given to a file tool
+ */
+export function normalizeInputPath(
+  input: string,
+  platform: NodeJS.Platform = process.platform
+): string {
+  // Paths pasted from a shell are often quoted
+  let normalized = input.trim().replace(/^(["'])(.+)\1$/, "$2");
+  if (platform !== "win32") return normalized;
+
+  // Long-path prefixes: \\?\C:\x -> C:\x, \\?\UNC\server\share -> \\server\share
+  normalized = normalized
+    .replace(/^[\\/]{2}[?.][\\/]UNC[\\/]/i, "\\\\")
+    .replace(/^[\\/]{2}[?.][\\/](?=[a-z]:)/i, "");
+
+  // MSYS / Git Bash drive paths: /c/Users/x -> C:\Users\x
+  const msys = normalized.match(/^\/([a-z])(?:\/(.*))?$/i);
+  if (msys) {
+    normalized = `${msys[1]}:\\${msys[2] ?? ""}`;
+  }
+
+  normalized = normalized.replace(/^([a-z]):/, (_, drive: string) => `${drive.toUpperCase()}:`);
+  return path.win32.normalize(normalized);
+}
+
+/**
+ * Whether a path is the directory itself or inside it
+ * Case-insensitive on Windows, where path.relative ignores case.
+ */
+export function isWithinDirectory(
+  target: string,
+  directory: string,
+  platform: NodeJS.Platform = process.platform
+): boolean {
+  const api = pathApi(platform);
+  const relative = api.relative(directory, target);
+  return (
+    relative === "" ||
+    (relative !== ".." && !relative.startsWith(`..${api.sep}`) && !api.isAbsolute(relative))
+  );
+}
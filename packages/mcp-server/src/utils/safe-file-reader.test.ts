@@ -0,0 +1,73 @@
+/**
+ * Safe File Reader Tests
+ */
+
+import { describe, it, expect, beforeAll, afterAll } from "vitest";
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import { readFileSafe } from "./safe-file-reader.js";
+
+const isWindows = process.platform === "win32";
+
+describe("readFileSafe", () => {
+  let dir: string;
+
+  beforeAll(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "safe-file-reader-"));
+    fs.writeFileSync(path.join(dir, "small.ts"), "export const a = 1;\r\n");
+    fs.writeFileSync(
+      path.join(dir, "big.log"),
+      Array.from({ length: 5000 }, (_, i) => `line ${i}`).join("\n")
+    );
+    fs.writeFileSync(path.join(dir, "image.png"), Buffer.from([0x89, 0x50, 0x4e, 0x47, 0x00, 0x00]));
+    if (!isWindows) {
+      fs.symlinkSync(path.join(dir, "loop-b"), path.join(dir, "loop-a"));
+      fs.symlinkSync(path.join(dir, "loop-a"), path.join(dir, "loop-b"));
+    }
+  });
+
+  afterAll(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("should read and decode text files", async () => {
+    const result = await readFileSafe(path.join(dir, "small.ts"));
+    expect(result).toMatchObject({ kind: "text", text: "export const a = 1;\n", encoding: "utf-8" });
+  });
+
+  it("should return head and tail of files above the cap", async () => {
+    const result = await readFileSafe(path.join(dir, "big.log"), { maxBytes: 10_000 });
+
+    expect(result.kind).toBe("too-large");
+    if (result.kind !== "too-large") return;
+    expect(result.head.startsWith("line 0\nline 1\n")).toBe(true);
+    expect(result.tail.endsWith("line 4998\nline 4999")).toBe(true);
+    // Partial lines at the window edges are dropped
+    expect(result.head.split("\n").every((l) => /^line \d+$/.test(l))).toBe(true);
+    expect(result.tail.split("\n").every((l) => /^line \d+$/.test(l))).toBe(true);
+  });
+
+  it("should flag binary files", async () => {
+    expect((await readFileSafe(path.join(dir, "image.png"))).kind).toBe("binary");
+  });
+
+  it("should refuse directories", async () => {
+    expect(await readFileSafe(dir)).toEqual({ kind: "unsupported", reason: "is a directory" });
+  });
+
+  it("should report missing files", async () => {
+    expect(await readFileSafe(path.join(dir, "missing.ts"))).toEqual({ kind: "not-found" });
+  });
+
+  it.skipIf(isWindows)("should refuse device files", async () => {
+    expect(await readFileSafe("/dev/null")).toEqual({ kind: "unsupported", reason: "is a device file" });
+  });
+
+  it.skipIf(isWindows)("should detect symlink loops", async () => {
+    expect(await readFileSafe(path.join(dir, "loop-a"))).toEqual({
+      kind: "unsupported",
+      reason: "is a symlink loop",
+    });
+  });
+});
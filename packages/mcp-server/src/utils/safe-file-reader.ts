@@ -0,0 +1,122 @@
+/**
+ * Safe File Reader
+ *
+ * Guarded reads for the file tools: refuses directories, devices, pipes
+ * and symlink loops, and returns only the head and tail of files above
+ * a size cap instead of loading them whole.
+ */
+
+import type { Stats } from "fs";
+import * as fs from "fs/promises";
+import { decodeAs, decodeText, detectEncoding, type TextEncodingName } from "./text-encoding.js";
+
+export const DEFAULT_MAX_FILE_BYTES = 2 * 1024 * 1024; // 2MB
+const PREVIEW_BYTES = 4096;
+
+export type SafeReadResult =
+  | { kind: "text"; text: string; encoding: TextEncodingName; size: number }
+  | {
+      kind: "too-large";
+      size: number;
+      /** Allocated blocks cover less than half the size */
+      sparse: boolean;
+      head: string;
+      tail: string;
+    }
+  | { kind: "binary"; size: number }
+  | { kind: "not-found" }
+  | { kind: "unsupported"; reason: string };
+
+export interface SafeReadOptions {
+  /** Files above this size return head/tail only (default: 2MB) */
+  maxBytes?: number;
+}
+
+/**
+ * Describe a non-regular file
+ */
+function describeSpecialFile(stats: Stats): string {
+  if (stats.isDirectory()) return "is a directory";
+  if (stats.isCharacterDevice() || stats.isBlockDevice()) return "is a device file";
+  if (stats.isFIFO()) return "is a named pipe";
+  if (stats.isSocket()) return "is a socket";
+  return "is not a regular file";
+}
+
+/**
+ * Read the first and last bytes of a large file as whole lines
+ */
+async function readHeadAndTail(
+  filePath: string,
+  size: number
+): Promise<{ head: string; tail: string } | null> {
+  const handle = await fs.open(filePath, "r");
+  try {
+    const headBuffer = Buffer.alloc(PREVIEW_BYTES);
+    const tailBuffer = Buffer.alloc(PREVIEW_BYTES);
+    const { bytesRead: headBytes } = await handle.read(headBuffer, 0, PREVIEW_BYTES, 0);
+    // Even offset keeps UTF-16 code units aligned
+    const tailStart = Math.max(0, size - PREVIEW_BYTES);
+    const { bytesRead: tailBytes } = await handle.read(
+      tailBuffer,
+      0,
+      PREVIEW_BYTES,
+      tailStart - (tailStart % 2)
+    );
+
+    // The tail has no BOM and may start mid-character, so decode it as the head
+    const encoding = detectEncoding(headBuffer.subarray(0, headBytes), true);
+    if (!encoding) return null;
+    const head = decodeAs(headBuffer.subarray(0, headBytes), encoding).replace(/\r\n/g, "\n");
+    const tail = decodeAs(tailBuffer.subarray(0, tailBytes), encoding).replace(/\r\n/g, "\n");
+
+    // Drop the partial lines cut by the byte windows
+    const headEnd = head.lastIndexOf("\n");
+    const tailLineStart = tail.indexOf("\n");
+    return {
+      head: headEnd > 0 ? head.slice(0, headEnd) : head,
+      tail: tailLineStart >= 0 ? tail.slice(tailLineStart + 1) : tail,
+    };
+  } finally {
+    await handle.close();
+  }
+}
+
+/**
+ * Read a file as text with size, type and loop guards
+ */
+export async function readFileSafe(
+  filePath: string,
+  options: SafeReadOptions = {}
+): Promise<SafeReadResult> {
+  const maxBytes = options.maxBytes ?? DEFAULT_MAX_FILE_BYTES;
+
+  let stats: Stats;
+  try {
+    stats = await fs.stat(filePath);
+  } catch (error) {
+    const code = (error as NodeJS.ErrnoException).code;
+    if (code === "ELOOP") return { kind: "unsupported", reason: "is a symlink loop" };
+    if (code === "ENOENT" || code === "ENOTDIR") return { kind: "not-found" };
+    return { kind: "unsupported", reason: `cannot be read (${code ?? "unknown error"})` };
+  }
+
+  if (!stats.isFile()) {
+    return { kind: "unsupported", reason: describeSpecialFile(stats) };
+  }
+
+  if (stats.size > maxBytes) {
+    const preview = await readHeadAndTail(filePath, stats.size);
+    if (!preview) return { kind: "binary", size: stats.size };
+    return {
+      kind: "too-large",
+      size: stats.size,
+      sparse: stats.blocks > 0 && stats.blocks * 512 < stats.size / 2,
+      ...preview,
+    };
+  }
+
+  const decoded = decodeText(await fs.readFile(filePath));
+  if (!decoded) return { kind: "binary", size: stats.size };
+  return { kind: "text", text: decoded.text, encoding: decoded.encoding, size: stats.size };
+}
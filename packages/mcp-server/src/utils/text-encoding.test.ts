@@ -0,0 +1,80 @@
+/**
+ * Text Encoding Tests
+ */
+
+import { describe, it, expect } from "vitest";
+import { decodeText, detectEncoding, detectLineEnding } from "./text-encoding.js";
+
+const SAMPLE = "const a = 1;\r\nconst b = 'é';\r\n";
+
+function utf16be(text: string): Buffer {
+  const buffer = Buffer.from(text, "utf16le");
+  buffer.swap16();
+  return buffer;
+}
+
+describe("text encoding", () => {
+  describe("detectEncoding", () => {
+    it("should detect BOMs", () => {
+      expect(detectEncoding(Buffer.from([0xef, 0xbb, 0xbf, 0x61]))).toBe("utf-8-bom");
+      expect(detectEncoding(Buffer.from([0xff, 0xfe, 0x61, 0x00]))).toBe("utf-16le");
+      expect(detectEncoding(Buffer.from([0xfe, 0xff, 0x00, 0x61]))).toBe("utf-16be");
+    });
+
+    it("should detect UTF-16 without a BOM", () => {
+      expect(detectEncoding(Buffer.from(SAMPLE, "utf16le"))).toBe("utf-16le");
+      expect(detectEncoding(utf16be(SAMPLE))).toBe("utf-16be");
+    });
+
+    it("should fall back to latin1 for invalid UTF-8", () => {
+      expect(detectEncoding(Buffer.from([0x63, 0x61, 0x66, 0xe9]))).toBe("latin1");
+    });
+
+    it("should accept a character cut at the end of a partial read", () => {
+      const cut = Buffer.from("café", "utf-8").subarray(0, 4);
+      expect(detectEncoding(cut, true)).toBe("utf-8");
+      expect(detectEncoding(cut)).toBe("latin1");
+    });
+
+    it("should treat NUL bytes as binary", () => {
+      const png = Buffer.from("89504e470d0a1a0a0000000d4948445200000010000000100806000000", "hex");
+      expect(detectEncoding(png)).toBeNull();
+    });
+  });
+
+  describe("decodeText", () => {
+    it("should strip the UTF-8 BOM and normalize CRLF", () => {
+      const decoded = decodeText(Buffer.concat([Buffer.from([0xef, 0xbb, 0xbf]), Buffer.from(SAMPLE)]));
+      expect(decoded).toEqual({
+        text: "const a = 1;\nconst b = 'é';\n",
+        encoding: "utf-8-bom",
+        lineEnding: "crlf",
+      });
+    });
+
+    it("should decode UTF-16 files written by Windows tools", () => {
+      const le = Buffer.concat([Buffer.from([0xff, 0xfe]), Buffer.from(SAMPLE, "utf16le")]);
+      const be = Buffer.concat([Buffer.from([0xfe, 0xff]), utf16be(SAMPLE)]);
+
+      expect(decodeText(le)?.text).toBe("const a = 1;\nconst b = 'é';\n");
+      expect(decodeText(be)?.text).toBe("const a = 1;\nconst b = 'é';\n");
+    });
+
+    it("should decode latin1", () => {
+      expect(decodeText(Buffer.from([0x63, 0x61, 0x66, 0xe9]))?.text).toBe("café");
+    });
+
+    it("should return null for binary content", () => {
+      expect(decodeText(Buffer.from([0x00, 0x01, 0x02, 0x03, 0x00]))).toBeNull();
+    });
+  });
+
+  describe("detectLineEnding", () => {
+    it("should classify line endings", () => {
+      expect(detectLineEnding("a\nb\n")).toBe("lf");
+      expect(detectLineEnding("a\r\nb\r\n")).toBe("crlf");
+      expect(detectLineEnding("a\r\nb\n")).toBe("mixed");
+      expect(detectLineEnding("a")).toBe("none");
+    });
+  });
+});
@@ -0,0 +1,116 @@
+/**
+ * Text Encoding
+ *
+ * Decodes file contents for the file tools: sniffs BOMs and BOM-less
+ * UTF-16, falls back to Latin-1 for invalid UTF-8, rejects binary
+ * data and normalizes CRLF line endings.
+ */
+
+export type TextEncodingName = "utf-8" | "utf-8-bom" | "utf-16le" | "utf-16be" | "latin1";
+
+export type LineEnding = "lf" | "crlf" | "mixed" | "none";
+
+export interface DecodedText {
+  /** Decoded text with CRLF normalized to LF */
+  text: string;
+  encoding: TextEncodingName;
+  /** Line endings found in the original text */
+  lineEnding: LineEnding;
+}
+
+// Bytes inspected for UTF-16 and binary detection
+const SNIFF_BYTES = 8000;
+
+/**
+ * Detect UTF-16 without a BOM from the NUL byte pattern of ASCII text
+ */
+function sniffUtf16(buffer: Buffer): "utf-16le" | "utf-16be" | null {
+  const length = Math.min(buffer.length, SNIFF_BYTES) & ~1;
+  if (length < 16) return null;
+
+  let evenZeros = 0;
+  let oddZeros = 0;
+  for (let i = 0; i < length; i += 2) {
+    if (buffer[i] === 0) evenZeros++;
+    if (buffer[i + 1] === 0) oddZeros++;
+  }
+
+  const pairs = length / 2;
+  if (oddZeros > pairs * 0.4 && evenZeros < pairs * 0.05) return "utf-16le";
+  if (evenZeros > pairs * 0.4 && oddZeros < pairs * 0.05) return "utf-16be";
+  return null;
+}
+
+/**
+ * Detect the encoding of a buffer; null means binary
+ * Set partial when the buffer is a prefix of the file, so a multi-byte
+ * character cut at the end is not taken for invalid UTF-8.
+ */
+export function detectEncoding(buffer: Buffer, partial: boolean = false): TextEncodingName | null {
+  if (buffer[0] === 0xef && buffer[1] === 0xbb && buffer[2] === 0xbf) return "utf-8-bom";
+  if (buffer[0] === 0xff && buffer[1] === 0xfe) return "utf-16le";
+  if (buffer[0] === 0xfe && buffer[1] === 0xff) return "utf-16be";
+
+  const utf16 = sniffUtf16(buffer);
+  if (utf16) return utf16;
+
+  if (buffer.subarray(0, SNIFF_BYTES).includes(0)) return null;
+
+  try {
+    new TextDecoder("utf-8", { fatal: true }).decode(buffer, { stream: partial });
+    return "utf-8";
+  } catch {
+    return "latin1";
+  }
+}
+
+/**
+ * Classify the line endings of a text
+ */
+export function detectLineEnding(text: string): LineEnding {
+  const crlf = (text.match(/\r\n/g) ?? []).length;
+  const lf = (text.match(/\n/g) ?? []).length - crlf;
+
+  if (crlf === 0 && lf === 0) return "none";
+  if (crlf === 0) return "lf";
+  return lf === 0 ? "crlf" : "mixed";
+}
+
+/**
+ * Decode a buffer with a known encoding, dropping a leading BOM
+ */
+export function decodeAs(buffer: Buffer, encoding: TextEncodingName): string {
+  switch (encoding) {
+    case "utf-8-bom": {
+      const hasBom = buffer[0] === 0xef && buffer[1] === 0xbb && buffer[2] === 0xbf;
+      return buffer.subarray(hasBom ? 3 : 0).toString("utf-8");
+    }
+    case "utf-16le":
+      return new TextDecoder("utf-16le").decode(buffer);
+    case "utf-16be": {
+      // Node has no UTF-16BE codec; swap to LE (copy so the caller's buffer is untouched)
+      const swapped = Buffer.from(buffer.subarray(0, buffer.length & ~1));
+      swapped.swap16();
+      return new TextDecoder("utf-16le").decode(swapped);
+    }
+    case "latin1":
+      return buffer.toString("latin1");
+    default:
+      return buffer.toString("utf-8");
+  }
+}
+
+/**
+ * Decode a buffer to text; null when the content is binary
+ */
+export function decodeText(buffer: Buffer): DecodedText | null {
+  const encoding = detectEncoding(buffer);
+  if (!encoding) return null;
+
+  const raw = decodeAs(buffer, encoding);
+  return {
+    text: raw.replace(/\r\n/g, "\n"),
+    encoding,
+    lineEnding: detectLineEnding(raw),
+  };
+}